@@ -0,0 +1,408 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::io::BufRead;
+
+use aoc_common::{Answer, MergeError, Solution, SolveError};
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum AOCError {
+    #[error("Line {line} does not contain a digit: `{content}`")]
+    NoDigitError { line: usize, content: String },
+    #[error("`{0}` cannot be used as a word for the digit {1}")]
+    InvalidDigitWordError(String, u32),
+    #[error("Could not read input: {0}")]
+    ReadError(String),
+}
+
+/// Split the input into lines. Empty lines are kept so that the index of a
+/// line matches its position in the input, they are skipped when solving.
+pub fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+/// Non-empty lines together with their 1-based line number.
+fn numbered_lines<'a>(input: &'a [&'a str]) -> impl Iterator<Item = (usize, &'a str)> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, *line))
+        .filter(|(_, line)| !line.is_empty())
+}
+
+/// Calibration value of the given line, or an error if it has no digits.
+fn calibration_value(line: usize, content: &str, matcher: &DigitMatcher) -> Result<u32, AOCError> {
+    matcher
+        .calibration_value(content)
+        .ok_or_else(|| AOCError::NoDigitError {
+            line,
+            content: content.to_string(),
+        })
+}
+
+const NUMBER_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Finds digits in a line, both as numeric characters and as words from a
+/// dictionary.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DigitMatcher {
+    /// Sorted from longest to shortest word, so that the longest word wins
+    /// if multiple words start at the same position.
+    words: Vec<(String, u32)>,
+}
+
+impl DigitMatcher {
+    /// Create a matcher for the given words. Every word has to be non-empty
+    /// and stand for a single digit.
+    pub fn new<S: Into<String>>(
+        words: impl IntoIterator<Item = (S, u32)>,
+    ) -> Result<DigitMatcher, AOCError> {
+        let mut words = words
+            .into_iter()
+            .map(|(word, value)| {
+                let word = word.into();
+                if word.is_empty() || value > 9 {
+                    Err(AOCError::InvalidDigitWordError(word, value))
+                } else {
+                    Ok((word, value))
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        words.sort_by_key(|(word, _)| core::cmp::Reverse(word.len()));
+        Ok(DigitMatcher { words })
+    }
+
+    /// Only match numeric characters.
+    pub fn digits_only() -> DigitMatcher {
+        DigitMatcher { words: Vec::new() }
+    }
+
+    /// The words "one" to "nine".
+    pub fn english() -> DigitMatcher {
+        DigitMatcher::new(NUMBER_WORDS.into_iter().zip(1..)).expect("The English words are valid")
+    }
+
+    /// Digits in `line`, from left to right. Words may overlap, so with the
+    /// English words "eightwo" yields 8 and 2.
+    pub fn digits<'a>(&'a self, line: &'a str) -> impl DoubleEndedIterator<Item = u32> + 'a {
+        line.char_indices().filter_map(|(idx, c)| {
+            c.to_digit(10).or_else(|| {
+                let rest = &line[idx..];
+                self.words
+                    .iter()
+                    .find(|(word, _)| rest.starts_with(word.as_str()))
+                    .map(|(_, value)| *value)
+            })
+        })
+    }
+
+    /// The first and last digit of `line` combined into a two-digit number,
+    /// `None` if the line does not contain a digit.
+    pub fn calibration_value(&self, line: &str) -> Option<u32> {
+        if self.words.is_empty() {
+            return ascii_calibration_value(line.as_bytes());
+        }
+        let mut digits = self.digits(line);
+        let first = digits.next()?;
+        let last = digits.next_back().unwrap_or(first);
+        Some(first * 10 + last)
+    }
+}
+
+/// Like `DigitMatcher::calibration_value` without words. The digits are
+/// ASCII, so it's enough to scan the bytes from both ends of the line, which
+/// the compiler vectorizes, instead of decoding every character.
+fn ascii_calibration_value(line: &[u8]) -> Option<u32> {
+    let first = line.iter().position(u8::is_ascii_digit)?;
+    let last = line.iter().rposition(u8::is_ascii_digit).unwrap_or(first);
+    Some(u32::from(line[first] - b'0') * 10 + u32::from(line[last] - b'0'))
+}
+
+impl Default for DigitMatcher {
+    fn default() -> Self {
+        DigitMatcher::english()
+    }
+}
+
+/// Sum of the calibration values of all lines, using `matcher` to find the
+/// digits. The sum is a `u64`, a `u32` overflows after about 43 million lines.
+pub fn process_with(input: &[&str], matcher: &DigitMatcher) -> Result<u64, AOCError> {
+    numbered_lines(input)
+        .map(|(line, content)| calibration_value(line, content, matcher).map(u64::from))
+        .sum()
+}
+
+pub fn process_part1(input: &[&str]) -> Result<u64, AOCError> {
+    process_with(input, &DigitMatcher::digits_only())
+}
+
+pub fn process_part2(input: &[&str]) -> Result<u64, AOCError> {
+    process_with(input, &DigitMatcher::english())
+}
+
+/// Like `process_part1` for the whole input as bytes, for large inputs that
+/// are not split into lines first. The line ends are found with `memchr`.
+pub fn process_part1_bytes(input: &[u8]) -> Result<u64, AOCError> {
+    let mut sum = 0;
+    let mut rest = input;
+    let mut line = 0;
+    while !rest.is_empty() {
+        line += 1;
+        let end = memchr::memchr(b'\n', rest).unwrap_or(rest.len());
+        let content = &rest[..end];
+        rest = rest.get(end + 1..).unwrap_or_default();
+        let content = content.strip_suffix(b"\r").unwrap_or(content);
+        if content.is_empty() {
+            continue;
+        }
+        let value = ascii_calibration_value(content).ok_or_else(|| AOCError::NoDigitError {
+            line,
+            content: String::from_utf8_lossy(content).into_owned(),
+        })?;
+        sum += u64::from(value);
+    }
+    Ok(sum)
+}
+
+/// Every line of `input` with its 1-based line number and calibration value,
+/// to find out how each line was interpreted. Lines without a digit, including
+/// empty ones, have no value.
+pub fn calibration_values<'a>(
+    input: &'a str,
+    matcher: &'a DigitMatcher,
+) -> impl Iterator<Item = (usize, &'a str, Option<u32>)> + 'a {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line, matcher.calibration_value(line)))
+}
+
+/// Like `process_with`, but reads the lines one at a time from `reader`
+/// instead of requiring the whole input in memory.
+#[cfg(feature = "std")]
+pub fn process_stream_with(
+    mut reader: impl BufRead,
+    matcher: &DigitMatcher,
+) -> Result<u64, AOCError> {
+    let mut sum = 0;
+    let mut buffer = String::new();
+    for line in 1.. {
+        buffer.clear();
+        if reader
+            .read_line(&mut buffer)
+            .map_err(|e| AOCError::ReadError(e.to_string()))?
+            == 0
+        {
+            break;
+        }
+        let content = buffer.trim_end_matches(['\n', '\r']);
+        if !content.is_empty() {
+            sum += u64::from(calibration_value(line, content, matcher)?);
+        }
+    }
+    Ok(sum)
+}
+
+#[cfg(feature = "std")]
+pub fn process_part1_stream(reader: impl BufRead) -> Result<u64, AOCError> {
+    process_stream_with(reader, &DigitMatcher::digits_only())
+}
+
+#[cfg(feature = "std")]
+pub fn process_part2_stream(reader: impl BufRead) -> Result<u64, AOCError> {
+    process_stream_with(reader, &DigitMatcher::english())
+}
+
+pub fn solve_both(input: &str) -> Result<(u64, u64), AOCError> {
+    let parsed_input = parse(input);
+    Ok((process_part1(&parsed_input)?, process_part2(&parsed_input)?))
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse(input))
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed)?.to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed)?.to_string())
+    }
+
+    fn merge(parsed: Vec<Self::Parsed<'_>>) -> Result<Self::Parsed<'_>, MergeError> {
+        Ok(parsed.into_iter().flatten().collect())
+    }
+}
+
+aoc_common::register_day!(Day01);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn test_process_part1() {
+        let input = vec!["1abc2", "pqr3stu8vwx", "a1b2c3d4e5f", "treb7uchet"];
+        let output = process_part1(&input);
+        assert_eq!(output, Ok(142))
+    }
+
+    #[test]
+    fn test_process_part1_bytes() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        assert_eq!(process_part1_bytes(input.as_bytes()), Ok(142));
+        assert_eq!(
+            process_part1_bytes("a1\r\n\r\nü9x4b\n".as_bytes()),
+            Ok(11 + 94)
+        );
+        assert_eq!(process_part1_bytes(b""), Ok(0));
+        assert_eq!(
+            process_part1_bytes(b"1\n\n\xffabc"),
+            Err(AOCError::NoDigitError {
+                line: 3,
+                content: "\u{fffd}abc".to_string()
+            })
+        );
+        // same as matching the characters
+        let matcher = DigitMatcher::digits_only();
+        for line in ["treb7uchet", "x", "٣4", "12ab٣"] {
+            let mut digits = matcher.digits(line);
+            let expected = digits
+                .next()
+                .map(|first| first * 10 + digits.next_back().unwrap_or(first));
+            assert_eq!(ascii_calibration_value(line.as_bytes()), expected, "{line}");
+        }
+    }
+
+    #[test]
+    fn test_missing_digit() {
+        let input = parse("1abc2\n\n# no digits here\n");
+        let error = AOCError::NoDigitError {
+            line: 3,
+            content: "# no digits here".to_string(),
+        };
+        assert_eq!(process_part1(&input), Err(error.clone()));
+        assert_eq!(process_part2(&input), Err(error.clone()));
+        assert_eq!(
+            error.to_string(),
+            "Line 3 does not contain a digit: `# no digits here`"
+        );
+    }
+
+    #[test]
+    fn test_english_digits() {
+        let matcher = DigitMatcher::english();
+        let tests = [
+            ("two1nine", vec![2, 1, 9]),
+            ("eightwothree", vec![8, 2, 3]),
+            ("abcone2threexyz", vec![1, 2, 3]),
+            ("zoneight234", vec![1, 8, 2, 3, 4]),
+            ("eightoneight", vec![8, 1, 8]),
+            ("3three7three118", vec![3, 3, 7, 3, 1, 1, 8]),
+            ("ünë2fïvesix", vec![2, 6]),
+        ];
+        for (input, expected) in tests {
+            assert_eq!(matcher.digits(input).collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn test_custom_digit_matcher() {
+        let german = DigitMatcher::new([
+            ("null", 0),
+            ("eins", 1),
+            ("zwei", 2),
+            ("sieben", 7),
+            ("siebzehn", 7),
+        ])
+        .unwrap();
+        assert_eq!(
+            german
+                .digits("nullzweiunddreißigsiebzehn")
+                .collect::<Vec<_>>(),
+            vec![0, 2, 7]
+        );
+        assert_eq!(process_with(&["zwei3sieben"], &german), Ok(27));
+
+        let ordinals = DigitMatcher::new([("first", 1), ("second", 2)]).unwrap();
+        assert_eq!(process_with(&["secondfirst"], &ordinals), Ok(21));
+
+        assert_eq!(
+            DigitMatcher::new([("ten", 10)]),
+            Err(AOCError::InvalidDigitWordError("ten".to_string(), 10))
+        );
+        assert!(DigitMatcher::new([("", 1)]).is_err());
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input = vec![
+            "two1nine",
+            "eightwothree",
+            "abcone2threexyz",
+            "xtwone3four",
+            "4nineeightseven2",
+            "zoneight234",
+            "7pqrstsixteen",
+        ];
+        let output = process_part2(&input);
+        assert_eq!(output, Ok(281))
+    }
+
+    #[test]
+    fn test_calibration_values() {
+        let matcher = DigitMatcher::english();
+        let values: Vec<_> = calibration_values("two1nine\n\nabc\n7", &matcher).collect();
+        assert_eq!(
+            values,
+            vec![
+                (1, "two1nine", Some(29)),
+                (2, "", None),
+                (3, "abc", None),
+                (4, "7", Some(77)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_process_stream() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        assert_eq!(process_part1_stream(input.as_bytes()), Ok(142));
+        assert_eq!(
+            process_part2_stream("two1nine\r\n\r\nabcone2threexyz".as_bytes()),
+            Ok(29 + 13)
+        );
+        assert_eq!(
+            process_part1_stream("1\n\nabc\n".as_bytes()),
+            Err(AOCError::NoDigitError {
+                line: 3,
+                content: "abc".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_solve_both() {
+        let input = "1abc2\ntwo1nine\nzoneight234\n";
+        assert_eq!(solve_both(input), Ok((12 + 11 + 24, 12 + 29 + 14)))
+    }
+}
@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_01::Day01, part2, "input_part2.txt");
@@ -0,0 +1,565 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    borrow::Cow,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::str::FromStr;
+
+use aoc_common::{text::line_column, Answer, MergeError, Solution, SolveError};
+use thiserror::Error;
+use winnow::{
+    ascii::{dec_uint, space0, space1},
+    combinator::{cut_err, eof, preceded, separated},
+    error::{ContextError, ParseError, StrContext, StrContextValue},
+    token::take_till,
+    ModalResult, Parser,
+};
+
+/// A game, the color names borrow from the parsed input.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Game<'a> {
+    id: u32,
+    infos: Vec<GameInfo<'a>>,
+}
+
+impl<'a> Game<'a> {
+    fn new(id: u32, infos: Vec<GameInfo<'a>>) -> Game<'a> {
+        Game { id, infos }
+    }
+
+    /// Parse a game without copying the color names, see `FromStr` for a
+    /// game that owns them.
+    pub fn parse(s: &'a str) -> Result<Game<'a>, GameParseError> {
+        game.parse(s).map_err(|e| GameParseError::new(s, &e))
+    }
+
+    pub fn into_owned(self) -> Game<'static> {
+        Game::new(
+            self.id,
+            self.infos.into_iter().map(CubeSet::into_owned).collect(),
+        )
+    }
+
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// The sets of cubes drawn in this game, in order.
+    pub fn draws(&self) -> impl ExactSizeIterator<Item = &GameInfo<'a>> {
+        self.infos.iter()
+    }
+
+    pub fn is_possible(&self, max_values: &GameInfo<'_>) -> bool {
+        self.infos.iter().all(|info| info.is_subset_of(max_values))
+    }
+
+    /// The fewest cubes of each color that make all draws of the game
+    /// possible.
+    pub fn minimum_set(&self) -> GameInfo<'a> {
+        self.infos
+            .iter()
+            .fold(CubeSet::default(), |minimum, info| minimum.union(info))
+    }
+
+    pub fn power(&self) -> u32 {
+        self.minimum_set().power()
+    }
+}
+
+/// Number of cubes per color. Colors that are not part of the set have a
+/// count of zero and are not stored.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "BTreeMap<String, u32>", into = "BTreeMap<String, u32>")
+)]
+pub struct CubeSet<'a> {
+    counts: BTreeMap<Cow<'a, str>, u32>,
+}
+
+/// A single draw of cubes from the bag.
+pub type GameInfo<'a> = CubeSet<'a>;
+
+impl<'a> CubeSet<'a> {
+    /// A set with the three colors of the original puzzle.
+    pub fn new(r: u32, g: u32, b: u32) -> CubeSet<'a> {
+        CubeSet::from_iter([("red", r), ("green", g), ("blue", b)])
+    }
+
+    pub fn into_owned(self) -> CubeSet<'static> {
+        self.counts
+            .into_iter()
+            .map(|(color, count)| (Cow::Owned(color.into_owned()), count))
+            .collect()
+    }
+
+    pub fn get(&self, color: &str) -> u32 {
+        self.counts.get(color).copied().unwrap_or(0)
+    }
+
+    pub fn insert(&mut self, color: impl Into<Cow<'a, str>>, count: u32) {
+        let color = color.into();
+        if count == 0 {
+            self.counts.remove(&color);
+        } else {
+            self.counts.insert(color, count);
+        }
+    }
+
+    /// Colors with a non-zero count, in alphabetical order.
+    pub fn colors(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.counts
+            .iter()
+            .map(|(color, count)| (color.as_ref(), *count))
+    }
+
+    /// Whether the cubes of this set could all be drawn from `other`.
+    pub fn is_subset_of(&self, other: &CubeSet<'_>) -> bool {
+        self.colors()
+            .all(|(color, count)| count <= other.get(color))
+    }
+
+    /// Smallest set containing both sets, the maximum count of every color.
+    pub fn union(mut self, other: &CubeSet<'a>) -> CubeSet<'a> {
+        for (color, &count) in &other.counts {
+            if count > self.get(color) {
+                self.insert(color.clone(), count);
+            }
+        }
+        self
+    }
+
+    /// Product of the red, green and blue counts, as in the puzzle. Other
+    /// colors don't count, and a missing color makes the power zero.
+    pub fn power(&self) -> u32 {
+        self.get("red") * self.get("green") * self.get("blue")
+    }
+}
+
+impl From<BTreeMap<String, u32>> for CubeSet<'_> {
+    fn from(counts: BTreeMap<String, u32>) -> Self {
+        counts.into_iter().collect()
+    }
+}
+
+impl From<CubeSet<'_>> for BTreeMap<String, u32> {
+    fn from(set: CubeSet<'_>) -> Self {
+        set.counts
+            .into_iter()
+            .map(|(color, count)| (color.into_owned(), count))
+            .collect()
+    }
+}
+
+impl<'a, S: Into<Cow<'a, str>>> FromIterator<(S, u32)> for CubeSet<'a> {
+    fn from_iter<I: IntoIterator<Item = (S, u32)>>(iter: I) -> Self {
+        let mut set = CubeSet::default();
+        for (color, count) in iter {
+            set.insert(color, count);
+        }
+        set
+    }
+}
+
+/// A game that could not be parsed, `column` is 1-based and counts
+/// characters.
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("Expected {expected} at column {column}")]
+pub struct GameParseError {
+    pub column: usize,
+    pub expected: String,
+}
+
+impl GameParseError {
+    fn new(input: &str, error: &ParseError<&str, ContextError>) -> GameParseError {
+        let expected = error
+            .inner()
+            .context()
+            .find_map(|context| match context {
+                StrContext::Expected(value) => Some(value.to_string()),
+                _ => None,
+            })
+            .unwrap_or_else(|| "a game".to_string());
+        GameParseError {
+            column: line_column(input, error.offset()).1,
+            expected,
+        }
+    }
+}
+
+/// A line of the input that could not be parsed.
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error(
+    "Line {line}, column {}: expected {}: `{content}`",
+    error.column,
+    error.expected
+)]
+pub struct ParseInputError {
+    pub line: usize,
+    pub content: String,
+    pub error: GameParseError,
+}
+
+/// A game whose re-numbered id does not fit into an `u32` after merging,
+/// `list` is the 0-based index of its list.
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("The id of game {id} of list {list} overflows when merging")]
+pub struct IdOverflowError {
+    pub list: usize,
+    pub id: u32,
+}
+
+fn expected(description: &'static str) -> StrContext {
+    StrContext::Expected(StrContextValue::Description(description))
+}
+
+/// `<count> <color>`, the color is everything up to the next `,` or `;`.
+fn cubes<'a>(input: &mut &'a str) -> ModalResult<(&'a str, u32)> {
+    preceded(
+        space0,
+        cut_err((
+            dec_uint.context(expected("a cube count")),
+            preceded(space1, take_till(1.., [',', ';']))
+                .map(str::trim_end)
+                .context(expected("a color")),
+        )),
+    )
+    .map(|(count, color)| (color, count))
+    .parse_next(input)
+}
+
+fn game<'a>(input: &mut &'a str) -> ModalResult<Game<'a>> {
+    let draw = separated(1.., cubes, ',').map(|cubes: Vec<_>| CubeSet::from_iter(cubes));
+    (
+        preceded((space0, "Game", space1), dec_uint).context(expected("`Game <id>`")),
+        preceded(
+            (space0, ':'.context(expected("`:`"))),
+            separated(1.., draw, ';'),
+        ),
+        eof.context(expected("`,` or `;`")),
+    )
+        .map(|(id, infos, _)| Game::new(id, infos))
+        .parse_next(input)
+}
+
+impl FromStr for Game<'static> {
+    type Err = GameParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Game::parse(s).map(Game::into_owned)
+    }
+}
+
+/// Parse one game per line, empty lines are skipped.
+pub fn parse_input(input: &str) -> Result<Vec<Game<'_>>, ParseInputError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            Game::parse(line).map_err(|error| ParseInputError {
+                line: idx + 1,
+                content: line.to_string(),
+                error,
+            })
+        })
+        .collect()
+}
+
+/// Merge several lists of games into one, re-numbering the game ids so that
+/// they continue after the highest id of the previous list.
+pub fn merge<'a>(
+    lists: impl IntoIterator<Item = Vec<Game<'a>>>,
+) -> Result<Vec<Game<'a>>, IdOverflowError> {
+    let mut merged: Vec<Game> = Vec::new();
+    // the highest id of the merged games
+    let mut offset = 0;
+    for (list, games) in lists.into_iter().enumerate() {
+        let mut max_id = offset;
+        for mut game in games {
+            game.id = game
+                .id
+                .checked_add(offset)
+                .ok_or(IdOverflowError { list, id: game.id })?;
+            max_id = max_id.max(game.id);
+            merged.push(game);
+        }
+        offset = max_id;
+    }
+    Ok(merged)
+}
+
+/// The games matching `predicate`, for example the games where a single draw
+/// shows more than 10 blue cubes:
+///
+/// ```
+/// # let games = aoc2023_day_02::parse_input("Game 1: 11 blue; 2 red").unwrap();
+/// let games: Vec<_> = aoc2023_day_02::filter_games(&games, |game| {
+///     game.draws().any(|draw| draw.get("blue") > 10)
+/// })
+/// .collect();
+/// assert_eq!(games.len(), 1);
+/// ```
+pub fn filter_games<'a, 'b>(
+    games: &'a [Game<'b>],
+    mut predicate: impl FnMut(&Game) -> bool + 'a,
+) -> impl Iterator<Item = &'a Game<'b>> + 'a {
+    games.iter().filter(move |game| predicate(game))
+}
+
+pub fn process_part1(input: &[Game], max_values: &GameInfo) -> u32 {
+    input
+        .iter()
+        .filter_map(|game| {
+            if game.is_possible(max_values) {
+                return Some(game.id);
+            }
+            None
+        })
+        .sum()
+}
+
+pub fn process_part2(input: &[Game]) -> u32 {
+    input.iter().map(|game| game.power()).sum()
+}
+
+pub fn solve_both(input: &str, max_values: &GameInfo) -> Result<(u32, u32), ParseInputError> {
+    let parsed_input = parse_input(input)?;
+    Ok((
+        process_part1(&parsed_input, max_values),
+        process_part2(&parsed_input),
+    ))
+}
+
+pub struct Day02;
+
+impl Day02 {
+    /// The bag contents given in the puzzle for part 1.
+    pub fn default_limits() -> GameInfo<'static> {
+        GameInfo::new(12, 13, 14)
+    }
+
+    /// Solve part 1 for a bag containing `max_values`.
+    pub fn part1_with_limits(
+        parsed: &<Day02 as Solution>::Parsed<'_>,
+        max_values: &GameInfo,
+    ) -> Answer {
+        Ok(process_part1(parsed, max_values).to_string())
+    }
+}
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type Parsed<'a> = Vec<Game<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Day02::part1_with_limits(parsed, &Day02::default_limits())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed).to_string())
+    }
+
+    fn merge(parsed: Vec<Self::Parsed<'_>>) -> Result<Self::Parsed<'_>, MergeError> {
+        merge(parsed).map_err(|e| MergeError::Incompatible(e.to_string()))
+    }
+}
+
+aoc_common::register_day!(Day02);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn test_parse_game() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        let output = Game::new(
+            1,
+            vec![
+                GameInfo::new(4, 0, 3),
+                GameInfo::new(1, 2, 6),
+                GameInfo::new(0, 2, 0),
+            ],
+        );
+        assert_eq!(input.parse::<Game>().unwrap(), output)
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let tests = [
+            ("Game 1 3 blue", 8, "`:`"),
+            ("Game x: 3 blue", 6, "`Game <id>`"),
+            ("Round 1: 3 blue", 1, "`Game <id>`"),
+            ("Game 1: -3 blue", 9, "a cube count"),
+            ("Game 1: 3 blue, red", 17, "a cube count"),
+            ("Game 1: 3 blue;", 16, "a cube count"),
+            ("Game 1: 3 blue; 4", 18, "a color"),
+        ];
+        for (input, column, expected) in tests {
+            let expected = GameParseError {
+                column,
+                expected: expected.to_string(),
+            };
+            assert_eq!(input.parse::<Game>(), Err(expected), "{input}");
+        }
+
+        let error = parse_input("Game 1: 3 blue\n\nGame 2: 4 rød, x green\n").unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(
+            error.to_string(),
+            "Line 3, column 16: expected a cube count: `Game 2: 4 rød, x green`"
+        );
+    }
+
+    #[test]
+    fn test_parse_borrowed() {
+        let input = String::from("  Game  2 : 3 dark blue , 1 red ");
+        let game = Game::parse(&input).unwrap();
+        assert_eq!(game.id(), 2);
+        assert!(matches!(
+            game.infos[0].counts.keys().next(),
+            Some(Cow::Borrowed("dark blue"))
+        ));
+        assert_eq!(game.into_owned(), input.parse().unwrap());
+    }
+
+    #[test]
+    fn test_filter_games() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let games = parse_input(&input).unwrap();
+        assert_eq!(games[0].draws().len(), 3);
+
+        let ids: Vec<u32> =
+            filter_games(&games, |game| game.draws().any(|draw| draw.get("blue") > 5))
+                .map(|game| game.id())
+                .collect();
+        assert_eq!(ids, vec![1, 3, 4]);
+
+        let ids: Vec<u32> = filter_games(&games, |game| game.power() < 100)
+            .map(Game::id)
+            .collect();
+        assert_eq!(ids, vec![1, 2, 5]);
+    }
+
+    #[test]
+    fn test_minimum_set() {
+        let game: Game = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"
+            .parse()
+            .unwrap();
+        assert_eq!(game.minimum_set(), GameInfo::new(4, 2, 6));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let game: Game = "Game 3: 4 red; 1 red, 2 green".parse().unwrap();
+        let json = serde_json::to_string(&game).unwrap();
+        assert_eq!(json, r#"{"id":3,"infos":[{"red":4},{"green":2,"red":1}]}"#);
+        assert_eq!(serde_json::from_str::<Game>(&json).unwrap(), game);
+        assert_eq!(
+            serde_json::from_str::<CubeSet>(r#"{"red":4,"blue":0}"#).unwrap(),
+            GameInfo::new(4, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_other_colors() {
+        let game: Game = "Game 7: 2 red, 3 purple; 4 purple, 1 teal".parse().unwrap();
+        assert_eq!(game.infos[1].get("purple"), 4);
+        assert!(game.is_possible(&CubeSet::from_iter([
+            ("red", 2),
+            ("purple", 4),
+            ("teal", 1)
+        ])));
+        assert!(!game.is_possible(&GameInfo::new(12, 13, 14)));
+        // no green and blue cubes
+        assert_eq!(game.power(), 0);
+    }
+
+    #[test]
+    fn test_process_part1() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+            Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+            Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+            Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+            Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        let parsed_input = parse_input(input).unwrap();
+        let output = process_part1(&parsed_input, &GameInfo::new(12, 13, 14));
+        assert_eq!(output, 8)
+    }
+
+    #[test]
+    fn test_solve_both() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+            Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+        let output = solve_both(input, &GameInfo::new(12, 13, 14));
+        assert_eq!(output, Ok((1, 48 + 1560)))
+    }
+
+    #[test]
+    fn test_merge() {
+        let first = parse_input("Game 1: 3 blue\nGame 2: 4 red").unwrap();
+        let second = parse_input("Game 1: 1 green").unwrap();
+        let merged = merge([first, second]).unwrap();
+        assert_eq!(
+            merged.iter().map(|game| game.id).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(merged[2].infos, vec![GameInfo::new(0, 1, 0)]);
+
+        let first = parse_input("Game 4294967295: 3 blue").unwrap();
+        let second = parse_input("Game 1: 1 green").unwrap();
+        assert_eq!(
+            merge([first, second]),
+            Err(IdOverflowError { list: 1, id: 1 })
+        );
+    }
+
+    #[test]
+    fn test_get_power() {
+        let tests = [
+            ("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green", 48),
+            (
+                "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue",
+                12,
+            ),
+            (
+                "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
+                1560,
+            ),
+            (
+                "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red",
+                630,
+            ),
+            ("Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green", 36),
+        ];
+
+        for (input, expected) in tests {
+            let game: Game = input.parse().unwrap();
+            assert_eq!(game.power(), expected);
+        }
+    }
+
+    #[test]
+    fn test_power_missing_colors() {
+        let game: Game = "Game 1: 3 blue, 4 red; 1 red, 6 blue".parse().unwrap();
+        assert_eq!(game.power(), 0);
+        assert_eq!(GameInfo::new(4, 0, 6).power(), 0);
+        assert_eq!(CubeSet::default().power(), 0);
+    }
+}
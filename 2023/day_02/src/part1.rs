@@ -0,0 +1,58 @@
+use aoc2023_day_02::Day02;
+use aoc_common::{entrypoint::CommonArgs, Solution};
+use clap::Parser;
+
+/// Solve part 1, optionally with other bag contents than the puzzle's
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    common: CommonArgs,
+    /// Number of red cubes in the bag
+    #[arg(long)]
+    red: Option<u32>,
+    /// Number of green cubes in the bag
+    #[arg(long)]
+    green: Option<u32>,
+    /// Number of blue cubes in the bag
+    #[arg(long)]
+    blue: Option<u32>,
+    /// Number of cubes of any color in the bag as `color=count`, can be
+    /// repeated
+    #[arg(long, value_parser = parse_limit)]
+    max: Vec<(String, u32)>,
+}
+
+fn parse_limit(s: &str) -> Result<(String, u32), String> {
+    let (color, count) = s
+        .split_once('=')
+        .ok_or_else(|| format!("expected `color=count`, got `{s}`"))?;
+    let count = count
+        .parse()
+        .map_err(|_| format!("invalid count `{count}`"))?;
+    Ok((color.to_string(), count))
+}
+
+fn main() {
+    let args = Args::parse();
+    let mut limits = Day02::default_limits();
+    let colors = [
+        ("red", args.red),
+        ("green", args.green),
+        ("blue", args.blue),
+    ];
+    for (color, count) in colors {
+        if let Some(count) = count {
+            limits.insert(color, count);
+        }
+    }
+    for (color, count) in args.max {
+        limits.insert(color, count);
+    }
+
+    aoc_common::entrypoint::run_with::<Day02>(args.common, 1, "input.txt", |part, parsed, _| {
+        match part {
+            1 => Day02::part1_with_limits(parsed, &limits),
+            _ => Day02::part2(parsed),
+        }
+    });
+}
@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_02::Day02, part2);
@@ -0,0 +1,898 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::io::{self, BufRead};
+
+use aoc_common::{
+    collections::HashMap,
+    grid::{Grid, GridError},
+    Answer, Solution, SolveError,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AOCError {
+    #[cfg(feature = "std")]
+    #[error("Could not read the schematic: {0}")]
+    ReadError(#[from] io::Error),
+    #[error("The schematic is empty")]
+    EmptySchematicError,
+    /// `row` is the 1-based row number, empty lines are not counted.
+    #[error("Row {row} has {found} columns, expected {expected}: `{content}`")]
+    RaggedRowError {
+        row: usize,
+        expected: usize,
+        found: usize,
+        content: String,
+    },
+    /// Positions start at 0, as in the grid.
+    #[error("The ratio of the gear at ({x}, {y}) overflows")]
+    GearRatioOverflowError { x: usize, y: usize },
+    #[error("The sum overflows")]
+    OverflowError,
+    /// Positions start at 0, `x` is the column of the first digit.
+    #[error("The number at ({x}, {y}) is too large")]
+    NumberTooLargeError { x: usize, y: usize },
+}
+
+/// Rows of the schematic without line endings and trailing whitespace.
+fn schematic_rows(input: &str) -> Vec<&str> {
+    input.trim().lines().map(str::trim_end).collect()
+}
+
+fn to_grid(rows: &[&str], width: usize) -> Result<Grid<char>, AOCError> {
+    Grid::from_rows(rows.iter().map(|row| {
+        let mut cells: Vec<char> = row.chars().collect();
+        if cells.len() < width {
+            cells.resize(width, '.');
+        }
+        cells
+    }))
+    .map_err(|e| match e {
+        GridError::EmptyGridError => AOCError::EmptySchematicError,
+        GridError::RaggedRowError {
+            row,
+            expected,
+            found,
+        } => AOCError::RaggedRowError {
+            row: row + 1,
+            expected,
+            found,
+            content: rows[row].to_string(),
+        },
+    })
+}
+
+/// Parse the schematic, all rows need to have the same length.
+pub fn parse_input(input: &str) -> Result<Grid<char>, AOCError> {
+    to_grid(&schematic_rows(input), 0)
+}
+
+/// Like `parse_input`, but rows shorter than the longest row are padded with
+/// `.` instead of being an error.
+pub fn parse_input_padded(input: &str) -> Result<Grid<char>, AOCError> {
+    let rows = schematic_rows(input);
+    let width = rows
+        .iter()
+        .map(|row| row.chars().count())
+        .max()
+        .unwrap_or(0);
+    to_grid(&rows, width)
+}
+
+/// Which cells around a digit count as adjacent to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Adjacency {
+    /// Only the cells above, below, left and right of a digit.
+    Orthogonal,
+    /// Also the diagonal cells, as in the puzzle.
+    #[default]
+    EightNeighbors,
+}
+
+/// What counts as a symbol and as a gear.
+#[derive(Debug, Clone, Copy)]
+pub struct ProcessOptions {
+    /// Whether a character is a symbol. Digits are never symbols.
+    pub is_symbol: fn(char) -> bool,
+    /// The symbol marking a possible gear.
+    pub gear_symbol: char,
+    /// Number of adjacent numbers that make a gear symbol a gear.
+    pub gear_arity: usize,
+    pub adjacency: Adjacency,
+}
+
+impl Default for ProcessOptions {
+    fn default() -> Self {
+        ProcessOptions {
+            is_symbol: |char| char != '.',
+            gear_symbol: '*',
+            gear_arity: 2,
+            adjacency: Adjacency::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Symbol {
+    pub x: usize,
+    pub y: usize,
+    pub char: char,
+}
+
+/// A number of the schematic, spanning the columns `x_start..=x_end` of row
+/// `y`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchematicNumber {
+    pub value: u64,
+    pub y: usize,
+    pub x_start: usize,
+    pub x_end: usize,
+    /// The symbols adjacent to the number, ordered by position.
+    pub symbols: Vec<Symbol>,
+    /// The gear symbols adjacent to the number, also if they don't count as
+    /// symbols.
+    pub gear_symbols: Vec<Symbol>,
+}
+
+impl SchematicNumber {
+    pub fn is_part_number(&self) -> bool {
+        !self.symbols.is_empty()
+    }
+}
+
+/// A gear symbol together with the numbers adjacent to it. It only is a gear
+/// if the number of them matches the gear arity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gear {
+    pub x: usize,
+    pub y: usize,
+    pub numbers: Vec<u64>,
+}
+
+impl Gear {
+    /// Product of the adjacent numbers.
+    pub fn ratio(&self) -> Result<u64, AOCError> {
+        gear_ratio(&self.numbers).ok_or(AOCError::GearRatioOverflowError {
+            x: self.x,
+            y: self.y,
+        })
+    }
+}
+
+fn gear_ratio(numbers: &[u64]) -> Option<u64> {
+    numbers
+        .iter()
+        .try_fold(1u64, |ratio, &number| ratio.checked_mul(number))
+}
+
+/// Every number of a schematic and every gear symbol next to a number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchematicReport {
+    /// All numbers of the schematic, row by row.
+    pub numbers: Vec<SchematicNumber>,
+    /// Ordered by position.
+    pub gears: Vec<Gear>,
+    /// Number of adjacent numbers that make a gear symbol a gear.
+    pub gear_arity: usize,
+}
+
+impl SchematicReport {
+    pub fn part_numbers(&self) -> impl Iterator<Item = &SchematicNumber> {
+        self.numbers.iter().filter(|number| number.is_part_number())
+    }
+
+    pub fn part_number_sum(&self) -> Result<u64, AOCError> {
+        checked_sum(self.part_numbers())
+    }
+
+    /// The gear symbols that are gears.
+    pub fn actual_gears(&self) -> impl Iterator<Item = &Gear> {
+        self.gears
+            .iter()
+            .filter(|gear| gear.numbers.len() == self.gear_arity)
+    }
+
+    pub fn gear_ratio_sum(&self) -> Result<u64, AOCError> {
+        self.actual_gears().try_fold(0u64, |sum, gear| {
+            sum.checked_add(gear.ratio()?)
+                .ok_or(AOCError::OverflowError)
+        })
+    }
+}
+
+fn checked_sum<'a>(
+    numbers: impl IntoIterator<Item = &'a SchematicNumber>,
+) -> Result<u64, AOCError> {
+    numbers
+        .into_iter()
+        .try_fold(0u64, |sum, number| sum.checked_add(number.value))
+        .ok_or(AOCError::OverflowError)
+}
+
+/// Sum of all numbers adjacent to a symbol. Unlike `analyze_with` this does
+/// not keep track of the gears.
+pub fn sum_part_numbers(schematic: &Grid<char>, options: &ProcessOptions) -> Result<u64, AOCError> {
+    checked_sum(
+        scan(schematic, options)?
+            .iter()
+            .filter(|number| number.is_part_number()),
+    )
+}
+
+/// Sum of the ratios of all gears.
+pub fn sum_gear_ratios(schematic: &Grid<char>, options: &ProcessOptions) -> Result<u64, AOCError> {
+    analyze_with(schematic, options)?.gear_ratio_sum()
+}
+
+pub fn solve_both(input: &str) -> Result<(u64, u64), AOCError> {
+    let report = analyze(&parse_input(input)?)?;
+    Ok((report.part_number_sum()?, report.gear_ratio_sum()?))
+}
+
+/// Find all numbers of the schematic, the symbols next to them and the
+/// possible gears.
+pub fn analyze(schematic: &Grid<char>) -> Result<SchematicReport, AOCError> {
+    analyze_with(schematic, &ProcessOptions::default())
+}
+
+pub fn analyze_with(
+    schematic: &Grid<char>,
+    options: &ProcessOptions,
+) -> Result<SchematicReport, AOCError> {
+    let numbers = scan(schematic, options)?;
+    let mut gears: BTreeMap<(usize, usize), Vec<u64>> = BTreeMap::new();
+    for number in &numbers {
+        for symbol in &number.gear_symbols {
+            gears
+                .entry((symbol.y, symbol.x))
+                .or_default()
+                .push(number.value);
+        }
+    }
+    let gears = gears
+        .into_iter()
+        .map(|((y, x), numbers)| Gear { x, y, numbers })
+        .collect();
+    Ok(SchematicReport {
+        numbers,
+        gears,
+        gear_arity: options.gear_arity,
+    })
+}
+
+/// All numbers of the schematic and the symbols adjacent to them, row by row.
+fn scan(
+    schematic: &Grid<char>,
+    options: &ProcessOptions,
+) -> Result<Vec<SchematicNumber>, AOCError> {
+    // rows are independent of each other, the gears are only collected once
+    // all numbers are known
+    #[cfg(feature = "parallel")]
+    let rows = {
+        use rayon::prelude::*;
+        (0..schematic.height())
+            .into_par_iter()
+            .map(|y| analyze_row(y, schematic, options))
+            .collect::<Result<Vec<_>, _>>()?
+    };
+    #[cfg(not(feature = "parallel"))]
+    let rows = (0..schematic.height())
+        .map(|y| analyze_row(y, schematic, options))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows.into_iter().flatten().collect())
+}
+
+/// All numbers in row `y`, from left to right.
+fn analyze_row(
+    y: usize,
+    schematic: &Grid<char>,
+    options: &ProcessOptions,
+) -> Result<Vec<SchematicNumber>, AOCError> {
+    let row = schematic.row(y);
+    let mut numbers = Vec::new();
+    let mut x = 0;
+    while x < row.len() {
+        let length = row[x..].iter().take_while(|c| c.is_ascii_digit()).count();
+        if length == 0 {
+            x += 1;
+            continue;
+        }
+        numbers.push(construct_new_number(
+            &row[x..x + length],
+            x,
+            y,
+            schematic,
+            options,
+        )?);
+        x += length;
+    }
+    Ok(numbers)
+}
+
+/// Like `solve_both` with the given options, but reads the schematic row by
+/// row from `reader` and only keeps the three rows needed for the adjacency
+/// checks in memory. Empty lines are skipped.
+#[cfg(feature = "std")]
+pub fn process_stream(
+    reader: impl BufRead,
+    options: &ProcessOptions,
+) -> Result<(u64, u64), AOCError> {
+    let mut window: Option<Grid<char>> = None;
+    // row number of the first row in the window and of the next row to analyze
+    let mut first_y = 0;
+    let mut current_y = 0;
+    let mut n_rows = 0;
+    let mut part_numbers = 0u64;
+    // gears that can still get more adjacent numbers, by (y, x)
+    let mut gears: BTreeMap<(usize, usize), Vec<u64>> = BTreeMap::new();
+    let mut gear_ratios = 0u64;
+
+    let mut finish_gears =
+        |gears: &mut BTreeMap<(usize, usize), Vec<u64>>, until_y: usize| -> Result<(), AOCError> {
+            while let Some(entry) = gears.first_entry() {
+                if entry.key().0 >= until_y {
+                    break;
+                }
+                let ((y, x), numbers) = entry.remove_entry();
+                if numbers.len() == options.gear_arity {
+                    let ratio =
+                        gear_ratio(&numbers).ok_or(AOCError::GearRatioOverflowError { x, y })?;
+                    gear_ratios = gear_ratios
+                        .checked_add(ratio)
+                        .ok_or(AOCError::OverflowError)?;
+                }
+            }
+            Ok(())
+        };
+
+    let mut analyze = |grid: &Grid<char>,
+                       first_y: usize,
+                       current_y: usize,
+                       gears: &mut BTreeMap<(usize, usize), Vec<u64>>|
+     -> Result<(), AOCError> {
+        let numbers = analyze_row(current_y - first_y, grid, options).map_err(|e| match e {
+            AOCError::NumberTooLargeError { x, .. } => {
+                AOCError::NumberTooLargeError { x, y: current_y }
+            }
+            e => e,
+        })?;
+        for number in numbers {
+            if number.is_part_number() {
+                part_numbers = part_numbers
+                    .checked_add(number.value)
+                    .ok_or(AOCError::OverflowError)?;
+            }
+            for symbol in &number.gear_symbols {
+                gears
+                    .entry((symbol.y + first_y, symbol.x))
+                    .or_default()
+                    .push(number.value);
+            }
+        }
+        Ok(())
+    };
+
+    for line in reader.lines() {
+        let line = line?;
+        let row: Vec<char> = line.trim_end().chars().collect();
+        if row.is_empty() {
+            continue;
+        }
+        let grid = match &mut window {
+            None => window.insert(Grid::from_rows([row]).expect("The row is not empty")),
+            Some(grid) => {
+                if row.len() != grid.width() {
+                    return Err(AOCError::RaggedRowError {
+                        row: n_rows + 1,
+                        expected: grid.width(),
+                        found: row.len(),
+                        content: line.trim_end().to_string(),
+                    });
+                }
+                grid.push_row(row)
+                    .expect("The row has the width of the grid");
+                grid
+            }
+        };
+        n_rows += 1;
+
+        // the row before the one just read has all its neighbors now
+        if n_rows >= current_y + 2 {
+            analyze(grid, first_y, current_y, &mut gears)?;
+            // numbers of later rows cannot touch gears above the current row
+            finish_gears(&mut gears, current_y)?;
+            current_y += 1;
+            while first_y + 1 < current_y {
+                grid.pop_first_row();
+                first_y += 1;
+            }
+        }
+    }
+    if let Some(grid) = &window {
+        while current_y < n_rows {
+            analyze(grid, first_y, current_y, &mut gears)?;
+            current_y += 1;
+        }
+    }
+    finish_gears(&mut gears, usize::MAX)?;
+    Ok((part_numbers, gear_ratios))
+}
+
+fn construct_new_number(
+    current_digits: &[char],
+    x_start: usize,
+    y: usize,
+    schematic: &Grid<char>,
+    options: &ProcessOptions,
+) -> Result<SchematicNumber, AOCError> {
+    let value = current_digits
+        .iter()
+        .collect::<String>()
+        .parse::<u64>()
+        .map_err(|_| AOCError::NumberTooLargeError { x: x_start, y })?;
+    let x_end = x_start + current_digits.len() - 1;
+    let adjacent = adjacent_symbols(x_start as i32, x_end as i32, y as i32, schematic, options);
+    let gear_symbols = adjacent
+        .iter()
+        .filter(|symbol| symbol.char == options.gear_symbol)
+        .copied()
+        .collect();
+    let symbols = adjacent
+        .into_iter()
+        .filter(|symbol| (options.is_symbol)(symbol.char))
+        .collect();
+    Ok(SchematicNumber {
+        value,
+        y,
+        x_start,
+        x_end,
+        symbols,
+        gear_symbols,
+    })
+}
+
+#[cfg_attr(
+    feature = "std",
+    tracing::instrument(level = "trace", skip(schematic, options))
+)]
+fn adjacent_symbols(
+    x_start: i32,
+    x_end: i32,
+    y: i32,
+    schematic: &Grid<char>,
+    options: &ProcessOptions,
+) -> Vec<Symbol> {
+    // a symbol can be adjacent to several digits of the number
+    let neighbors = |x| -> Box<dyn Iterator<Item = ((i32, i32), &char)>> {
+        match options.adjacency {
+            Adjacency::Orthogonal => Box::new(schematic.neighbors4(x, y)),
+            Adjacency::EightNeighbors => Box::new(schematic.neighbors8(x, y)),
+        }
+    };
+    let symbols: BTreeSet<Symbol> = (x_start..=x_end)
+        .flat_map(neighbors)
+        .filter(|(_, &char)| {
+            !char.is_ascii_digit() && ((options.is_symbol)(char) || char == options.gear_symbol)
+        })
+        .map(|((x, y), char)| Symbol {
+            x: x as usize,
+            y: y as usize,
+            char: *char,
+        })
+        .collect();
+    #[cfg(feature = "std")]
+    tracing::trace!("Adjacent symbols: {symbols:?}");
+    symbols.into_iter().collect()
+}
+
+const GREEN: &str = "\x1b[32m";
+const RED: &str = "\x1b[31m";
+const GEAR: &str = "\x1b[1;33m";
+const RESET: &str = "\x1b[0m";
+
+/// Render the schematic with ANSI colors: part numbers green, other numbers
+/// red and gears bold yellow. Every row ends with the sum of its part numbers
+/// and of the ratios of its gears, followed by a line with the totals.
+pub fn visualize(schematic: &Grid<char>, report: &SchematicReport) -> Result<String, AOCError> {
+    // the totals of the rows cannot overflow if the totals don't
+    let part_number_sum = report.part_number_sum()?;
+    let gear_ratio_sum = report.gear_ratio_sum()?;
+    let mut colors: HashMap<(usize, usize), &str> = HashMap::new();
+    let mut row_totals = vec![(0, 0); schematic.height()];
+    for number in &report.numbers {
+        let color = if number.is_part_number() {
+            row_totals[number.y].0 += number.value;
+            GREEN
+        } else {
+            RED
+        };
+        for x in number.x_start..=number.x_end {
+            colors.insert((x, number.y), color);
+        }
+    }
+    for gear in report.actual_gears() {
+        colors.insert((gear.x, gear.y), GEAR);
+        row_totals[gear.y].1 += gear.ratio()?;
+    }
+
+    let mut output = String::new();
+    for (y, row) in schematic.rows().enumerate() {
+        let mut current_color = None;
+        for (x, char) in row.iter().enumerate() {
+            let color = colors.get(&(x, y)).copied();
+            if color != current_color {
+                output.push_str(color.unwrap_or(RESET));
+                current_color = color;
+            }
+            output.push(*char);
+        }
+        if current_color.is_some() {
+            output.push_str(RESET);
+        }
+        let (part_numbers, gear_ratios) = row_totals[y];
+        output.push_str(&format!(
+            "  parts {part_numbers:>6}  gears {gear_ratios:>8}\n"
+        ));
+    }
+    output.push_str(&format!(
+        "{:width$}  parts {:>6}  gears {:>8}\n",
+        "total",
+        part_number_sum,
+        gear_ratio_sum,
+        width = schematic.width()
+    ));
+    Ok(output)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Parsed<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(sum_part_numbers(parsed, &ProcessOptions::default())?.to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(sum_gear_ratios(parsed, &ProcessOptions::default())?.to_string())
+    }
+
+    fn solve_both(input: &str) -> Result<(String, String), SolveError> {
+        let (part1, part2) = solve_both(input)?;
+        Ok((part1.to_string(), part2.to_string()))
+    }
+}
+
+aoc_common::register_day!(Day03);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn test_parse_input() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+
+        assert_eq!(parsed_input.get(0, 0), Some(&'4'));
+        assert_eq!(parsed_input.get(9, 0), Some(&'.'));
+        assert_eq!(parsed_input.get(5, 5), Some(&'+'));
+        assert_eq!(parsed_input.get(9, 9), Some(&'.'));
+    }
+
+    #[test]
+    fn test_parse_input_errors() {
+        let input = "467..114..\r\n...*......  \r\n..35..633\r\n";
+        let error = parse_input(input).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Row 3 has 9 columns, expected 10: `..35..633`"
+        );
+        assert!(matches!(
+            parse_input("\n\n"),
+            Err(AOCError::EmptySchematicError)
+        ));
+
+        let padded = parse_input_padded(input).unwrap();
+        assert_eq!(padded.width(), 10);
+        assert_eq!(padded.get(9, 2), Some(&'.'));
+        assert_eq!(
+            sum_part_numbers(&padded, &ProcessOptions::default()).unwrap(),
+            467 + 35
+        );
+    }
+
+    #[test]
+    fn test_analyze() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let report = analyze(&parse_input(&input).unwrap()).unwrap();
+
+        assert_eq!(report.numbers.len(), 10);
+        assert_eq!(
+            report.numbers[0],
+            SchematicNumber {
+                value: 467,
+                y: 0,
+                x_start: 0,
+                x_end: 2,
+                symbols: vec![Symbol {
+                    x: 3,
+                    y: 1,
+                    char: '*'
+                }],
+                gear_symbols: vec![Symbol {
+                    x: 3,
+                    y: 1,
+                    char: '*'
+                }],
+            }
+        );
+        let not_part_numbers: Vec<u64> = report
+            .numbers
+            .iter()
+            .filter(|number| !number.is_part_number())
+            .map(|number| number.value)
+            .collect();
+        assert_eq!(not_part_numbers, vec![114, 58]);
+
+        assert_eq!(
+            report.gears,
+            vec![
+                Gear {
+                    x: 3,
+                    y: 1,
+                    numbers: vec![467, 35]
+                },
+                Gear {
+                    x: 3,
+                    y: 4,
+                    numbers: vec![617]
+                },
+                Gear {
+                    x: 5,
+                    y: 8,
+                    numbers: vec![755, 598]
+                },
+            ]
+        );
+        assert_eq!(report.actual_gears().count(), 2);
+    }
+
+    #[test]
+    fn test_custom_options() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let schematic = parse_input(&input).unwrap();
+
+        let only_stars = ProcessOptions {
+            is_symbol: |char| char == '*',
+            ..Default::default()
+        };
+        assert_eq!(
+            sum_part_numbers(&schematic, &only_stars).unwrap(),
+            467 + 35 + 617 + 755 + 598
+        );
+
+        let single_gears = ProcessOptions {
+            gear_arity: 1,
+            ..Default::default()
+        };
+        assert_eq!(sum_gear_ratios(&schematic, &single_gears).unwrap(), 617);
+
+        let hash_gears = ProcessOptions {
+            gear_symbol: '#',
+            gear_arity: 1,
+            ..Default::default()
+        };
+        assert_eq!(sum_gear_ratios(&schematic, &hash_gears).unwrap(), 633);
+
+        // the gears don't need to be symbols
+        let no_star_symbols = ProcessOptions {
+            is_symbol: |char| char == '#',
+            ..Default::default()
+        };
+        assert_eq!(sum_part_numbers(&schematic, &no_star_symbols).unwrap(), 633);
+        assert_eq!(
+            sum_gear_ratios(&schematic, &no_star_symbols).unwrap(),
+            467835
+        );
+    }
+
+    #[test]
+    fn test_gear_ratio_overflow() {
+        let options = ProcessOptions {
+            gear_arity: 4,
+            ..Default::default()
+        };
+        let input = "999.999\n...*...\n999.999\n";
+        let ratio = 999u64.pow(4);
+        assert_eq!(
+            sum_gear_ratios(&parse_input(input).unwrap(), &options).unwrap(),
+            ratio
+        );
+        assert_eq!(
+            process_stream(input.as_bytes(), &options).unwrap(),
+            (4 * 999, ratio)
+        );
+
+        // 99999^4 does not fit into an u64
+        let input = "99999.99999\n.....*.....\n99999.99999\n";
+        assert!(matches!(
+            sum_gear_ratios(&parse_input(input).unwrap(), &options),
+            Err(AOCError::GearRatioOverflowError { x: 5, y: 1 })
+        ));
+        assert!(matches!(
+            process_stream(input.as_bytes(), &options),
+            Err(AOCError::GearRatioOverflowError { x: 5, y: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_process_stream() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let options = ProcessOptions::default();
+        assert_eq!(
+            process_stream(input.as_bytes(), &options).unwrap(),
+            (4361, 467835)
+        );
+        assert_eq!(
+            process_stream(input.replace('\n', "\r\n").as_bytes(), &options).unwrap(),
+            (4361, 467835)
+        );
+        assert_eq!(process_stream("".as_bytes(), &options).unwrap(), (0, 0));
+        assert_eq!(process_stream("1*\n".as_bytes(), &options).unwrap(), (1, 0));
+
+        let single_gears = ProcessOptions {
+            gear_arity: 1,
+            ..Default::default()
+        };
+        assert_eq!(
+            process_stream(input.as_bytes(), &single_gears).unwrap(),
+            (4361, 617)
+        );
+
+        assert!(matches!(
+            process_stream("..1\n.*\n".as_bytes(), &options),
+            Err(AOCError::RaggedRowError {
+                row: 2,
+                expected: 3,
+                found: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_large_numbers() {
+        let options = ProcessOptions::default();
+        // too large for an u32
+        let input = "4294967296*1\n";
+        assert_eq!(solve_both(input).unwrap(), (4294967297, 4294967296));
+        assert_eq!(
+            process_stream(input.as_bytes(), &options).unwrap(),
+            (4294967297, 4294967296)
+        );
+
+        let input = "18446744073709551615*1\n";
+        assert!(matches!(solve_both(input), Err(AOCError::OverflowError)));
+        assert!(matches!(
+            process_stream(input.as_bytes(), &options),
+            Err(AOCError::OverflowError)
+        ));
+
+        let input = "..\n.*18446744073709551616\n";
+        let schematic = parse_input_padded(input).unwrap();
+        assert!(matches!(
+            analyze(&schematic),
+            Err(AOCError::NumberTooLargeError { x: 2, y: 1 })
+        ));
+        let dots = ".".repeat(21);
+        let input = format!("{dots}\n{dots}\n*18446744073709551616\n");
+        assert!(matches!(
+            process_stream(input.as_bytes(), &options),
+            Err(AOCError::NumberTooLargeError { x: 1, y: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_visualize() {
+        let schematic = parse_input("1.*2\n..3.").unwrap();
+        let output = visualize(&schematic, &analyze(&schematic).unwrap()).unwrap();
+        assert_eq!(
+            output,
+            "\x1b[31m1\x1b[0m.\x1b[1;33m*\x1b[32m2\x1b[0m  parts      2  gears        6\n\
+             ..\x1b[32m3\x1b[0m.  parts      3  gears        0\n\
+             total  parts      5  gears        6\n"
+        );
+    }
+
+    #[test]
+    fn test_orthogonal_adjacency() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let schematic = parse_input(&input).unwrap();
+        let options = ProcessOptions {
+            adjacency: Adjacency::Orthogonal,
+            ..Default::default()
+        };
+        // 467, 592 and 755 only touch their symbols diagonally
+        assert_eq!(
+            sum_part_numbers(&schematic, &options).unwrap(),
+            35 + 633 + 617 + 664 + 598
+        );
+        assert_eq!(sum_gear_ratios(&schematic, &options).unwrap(), 0);
+        assert_eq!(
+            sum_part_numbers(&schematic, &options).unwrap(),
+            analyze_with(&schematic, &options)
+                .unwrap()
+                .part_number_sum()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_process_part1() {
+        // aoc_common::setup_tracing();
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = sum_part_numbers(&parsed_input, &ProcessOptions::default()).unwrap();
+        assert_eq!(output, 4361)
+    }
+
+    #[test]
+    fn test_process_part2() {
+        // aoc_common::setup_tracing();
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = sum_gear_ratios(&parsed_input, &ProcessOptions::default()).unwrap();
+        assert_eq!(output, 467835)
+    }
+
+    #[test]
+    fn test_solve_both() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        assert_eq!(solve_both(&input).unwrap(), (4361, 467835))
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let report = analyze(&parse_input(&input).unwrap()).unwrap();
+        let json = serde_json::to_string(&report).unwrap();
+        assert!(
+            json.contains(r#"{"x":3,"y":1,"numbers":[467,35]}"#),
+            "{json}"
+        );
+        assert_eq!(
+            serde_json::from_str::<SchematicReport>(&json).unwrap(),
+            report
+        );
+        assert_eq!(
+            serde_json::from_str::<Adjacency>(r#""Orthogonal""#).unwrap(),
+            Adjacency::Orthogonal
+        );
+    }
+}
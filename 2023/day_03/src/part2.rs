@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_03::Day03, part2);
@@ -0,0 +1,32 @@
+use std::process;
+
+use aoc2023_day_03::{analyze, parse_input, visualize};
+use aoc_common::input::InputArgs;
+use clap::Parser;
+
+/// Print the schematic with part numbers, other numbers and gears highlighted
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+fn main() {
+    let args = Args::parse();
+    let output = args
+        .input
+        .source("input.txt")
+        .read()
+        .map_err(|e| e.to_string())
+        .and_then(|input| parse_input(&input).map_err(|e| e.to_string()))
+        .and_then(|schematic| {
+            analyze(&schematic)
+                .and_then(|report| visualize(&schematic, &report))
+                .map_err(|e| e.to_string())
+        })
+        .unwrap_or_else(|e| {
+            eprintln!("{e}");
+            process::exit(1);
+        });
+    print!("{output}");
+}
@@ -0,0 +1,551 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use aoc_common::{collections::HashSet, Answer, MergeError, Solution, SolveError};
+use core::{num::ParseIntError, str::FromStr};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AOCError {
+    #[error("Did not find a colon in the input line")]
+    ParseErrorNoColon,
+    #[error("Cannot parse card id")]
+    ParseCardIdErorr,
+    #[error("Did not find a pipe in the input line")]
+    ParseErrorNoPipe,
+    #[error("Could not parse number: `{0}`")]
+    ParseNumberError(String),
+    #[error("Line {line}: {error}: `{content}`")]
+    InvalidLineError {
+        line: usize,
+        content: String,
+        error: Box<AOCError>,
+    },
+    #[error("Card {position} has id {id}, the ids need to be 1, 2, 3, ...")]
+    NonContiguousIdsError { position: usize, id: usize },
+    #[error("The points or the number of instances of card {id} overflow")]
+    OverflowError { id: usize },
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Card {
+    id: usize,
+    winning_numbers: HashSet<usize>,
+    numbers: Vec<usize>,
+    /// Id of the last card of the deck the card came from, if it was merged
+    /// with other decks. No copies are won past the end of a deck.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    deck_end: Option<usize>,
+}
+
+impl Card {
+    fn new(
+        id: usize,
+        winning_numbers: impl IntoIterator<Item = usize>,
+        numbers: Vec<usize>,
+    ) -> Card {
+        Card {
+            id,
+            winning_numbers: winning_numbers.into_iter().collect(),
+            numbers,
+            deck_end: None,
+        }
+    }
+
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    /// The numbers of the card that are winning numbers, in the order of the
+    /// card.
+    pub fn matching_numbers(&self) -> Vec<usize> {
+        self.numbers
+            .iter()
+            .filter(|n| self.winning_numbers.contains(*n))
+            .copied()
+            .collect()
+    }
+
+    fn amount_of_correct_numbers(&self) -> usize {
+        self.numbers
+            .iter()
+            .filter(|n| self.winning_numbers.contains(*n))
+            .count()
+    }
+
+    fn points(&self) -> Result<usize, AOCError> {
+        self.score(&Doubling)
+    }
+
+    pub fn score(&self, scoring: &impl Scoring) -> Result<usize, AOCError> {
+        scoring
+            .points(self.amount_of_correct_numbers())
+            .ok_or(AOCError::OverflowError { id: self.id })
+    }
+}
+
+/// How many points a card with a given number of correct numbers is worth,
+/// `None` if they overflow. Closures taking the number of correct numbers can
+/// be used as well.
+pub trait Scoring {
+    fn points(&self, n_correct_numbers: usize) -> Option<usize>;
+}
+
+/// One point for the first correct number, doubled for each further one.
+pub struct Doubling;
+
+impl Scoring for Doubling {
+    fn points(&self, n_correct_numbers: usize) -> Option<usize> {
+        match n_correct_numbers {
+            0 => Some(0),
+            n => 2_usize.checked_pow(u32::try_from(n - 1).ok()?),
+        }
+    }
+}
+
+/// One point for every correct number.
+pub struct Linear;
+
+impl Scoring for Linear {
+    fn points(&self, n_correct_numbers: usize) -> Option<usize> {
+        Some(n_correct_numbers)
+    }
+}
+
+/// One point if there is any correct number.
+pub struct FirstMatchOnly;
+
+impl Scoring for FirstMatchOnly {
+    fn points(&self, n_correct_numbers: usize) -> Option<usize> {
+        Some(n_correct_numbers.min(1))
+    }
+}
+
+impl<F: Fn(usize) -> usize> Scoring for F {
+    fn points(&self, n_correct_numbers: usize) -> Option<usize> {
+        Some(self(n_correct_numbers))
+    }
+}
+
+impl FromStr for Card {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (card_string, all_numbers) = s.split_once(':').ok_or(AOCError::ParseErrorNoColon)?;
+
+        let id: usize = card_string
+            .strip_prefix("Card")
+            .ok_or(AOCError::ParseCardIdErorr)?
+            .trim()
+            .parse()
+            .map_err(|_| AOCError::ParseCardIdErorr)?;
+
+        let (winning_numbers, numbers) = all_numbers
+            .split_once('|')
+            .ok_or(AOCError::ParseErrorNoPipe)?;
+
+        let winning_numbers = winning_numbers
+            .trim()
+            .split(' ')
+            .filter(|s| !s.is_empty())
+            .map(|num| {
+                num.parse()
+                    .map_err(|e: ParseIntError| AOCError::ParseNumberError(e.to_string()))
+            })
+            .collect::<Result<HashSet<_>, _>>()?;
+
+        let numbers = numbers
+            .trim()
+            .split(' ')
+            .filter(|s| !s.is_empty())
+            .map(|num| {
+                num.parse()
+                    .map_err(|e: ParseIntError| AOCError::ParseNumberError(e.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Card::new(id, winning_numbers, numbers))
+    }
+}
+
+/// Parse one card per line, empty lines are skipped.
+pub fn parse_input(input: &str) -> Result<Vec<Card>, AOCError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            line.parse::<Card>()
+                .map_err(|error| AOCError::InvalidLineError {
+                    line: idx + 1,
+                    content: line.to_string(),
+                    error: Box::new(error),
+                })
+        })
+        .collect()
+}
+
+/// Merge several decks into one, re-numbering the card ids so that they
+/// continue after the highest id of the previous deck. The cards remember
+/// the end of their deck, so that the copies of part 2 don't carry over into
+/// the next deck.
+pub fn merge(decks: impl IntoIterator<Item = Vec<Card>>) -> Vec<Card> {
+    let mut merged: Vec<Card> = Vec::new();
+    for cards in decks {
+        let offset = merged.iter().map(|card| card.id).max().unwrap_or(0);
+        let end = cards
+            .iter()
+            .map(|card| card.deck_end.unwrap_or(card.id))
+            .max()
+            .unwrap_or(0)
+            + offset;
+        merged.extend(cards.into_iter().map(|mut card| {
+            card.deck_end = Some(card.deck_end.map_or(end, |deck_end| deck_end + offset));
+            card.id += offset;
+            card
+        }));
+    }
+    merged
+}
+
+pub fn process_part1(cards: &[Card]) -> Result<usize, AOCError> {
+    process_part1_with(cards, &Doubling)
+}
+
+/// Total points of all cards using another scoring rule than part 1.
+pub fn process_part1_with(cards: &[Card], scoring: &impl Scoring) -> Result<usize, AOCError> {
+    let correct_numbers: Vec<usize> = cards
+        .iter()
+        .map(|c| c.amount_of_correct_numbers())
+        .collect();
+    total_points(cards, &correct_numbers, scoring)
+}
+
+pub fn process_part2(cards: &[Card]) -> Result<usize, AOCError> {
+    let correct_numbers: Vec<usize> = cards
+        .iter()
+        .map(|c| c.amount_of_correct_numbers())
+        .collect();
+    count_cards(cards, &correct_numbers)
+}
+
+pub fn solve_both(input: &str) -> Result<(usize, usize), AOCError> {
+    let cards = parse_input(input)?;
+    let correct_numbers: Vec<usize> = cards
+        .iter()
+        .map(|c| c.amount_of_correct_numbers())
+        .collect();
+    Ok((
+        total_points(&cards, &correct_numbers, &Doubling)?,
+        count_cards(&cards, &correct_numbers)?,
+    ))
+}
+
+/// Sum of the points of the cards, which grow exponentially with the correct
+/// numbers for part 1, so the sum is checked for overflow as well.
+fn total_points(
+    cards: &[Card],
+    correct_numbers: &[usize],
+    scoring: &impl Scoring,
+) -> Result<usize, AOCError> {
+    cards
+        .iter()
+        .zip(correct_numbers)
+        .try_fold(0_usize, |total, (card, &n_correct_numbers)| {
+            let overflow = || AOCError::OverflowError { id: card.id };
+            let points = scoring.points(n_correct_numbers).ok_or_else(overflow)?;
+            total.checked_add(points).ok_or_else(overflow)
+        })
+}
+
+fn count_cards(cards: &[Card], correct_numbers: &[usize]) -> Result<usize, AOCError> {
+    card_copies(cards, correct_numbers)?
+        .iter()
+        .enumerate()
+        .try_fold(0_usize, |total, (position, amount)| {
+            total
+                .checked_add(*amount)
+                .ok_or(AOCError::OverflowError { id: position + 1 })
+        })
+}
+
+/// Number of instances of each card after winning copies, the cards need to
+/// be ordered by id starting at 1. The counts grow exponentially with runs of
+/// winning cards, so they are checked for overflow.
+fn card_copies(cards: &[Card], correct_numbers: &[usize]) -> Result<Vec<usize>, AOCError> {
+    if let Some((position, card)) = cards
+        .iter()
+        .enumerate()
+        .find(|(position, card)| card.id != position + 1)
+    {
+        return Err(AOCError::NonContiguousIdsError {
+            position: position + 1,
+            id: card.id,
+        });
+    }
+
+    // Every card wins one copy of each of the following cards, so instead of
+    // updating the counts of all of them, keep a running sum of the won copies
+    // that still apply and remember when they stop applying.
+    let mut expiring = vec![0_usize; cards.len() + 1];
+    let mut won_copies: usize = 0;
+    let mut copies = Vec::with_capacity(cards.len());
+    for (position, n_correct_numbers) in correct_numbers.iter().enumerate() {
+        let overflow = || AOCError::OverflowError { id: position + 1 };
+        won_copies -= expiring[position];
+        let amount = won_copies.checked_add(1).ok_or_else(overflow)?;
+        copies.push(amount);
+        let deck_end = cards[position].deck_end.unwrap_or(cards.len());
+        let end = (position + 1)
+            .saturating_add(*n_correct_numbers)
+            .min(deck_end)
+            .min(cards.len());
+        if end > position + 1 {
+            won_copies = won_copies.checked_add(amount).ok_or_else(overflow)?;
+            expiring[end] = expiring[end].checked_add(amount).ok_or_else(overflow)?;
+        }
+    }
+    Ok(copies)
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CardReport {
+    pub id: usize,
+    pub matching_numbers: Vec<usize>,
+    /// Points of the card in part 1.
+    pub points: usize,
+    /// Number of instances of the card at the end of part 2.
+    pub copies: usize,
+}
+
+/// The details behind both parts for every card.
+pub fn report(cards: &[Card]) -> Result<Vec<CardReport>, AOCError> {
+    let correct_numbers: Vec<usize> = cards
+        .iter()
+        .map(|c| c.amount_of_correct_numbers())
+        .collect();
+    let copies = card_copies(cards, &correct_numbers)?;
+    cards
+        .iter()
+        .zip(copies)
+        .map(|(card, copies)| {
+            Ok(CardReport {
+                id: card.id,
+                matching_numbers: card.matching_numbers(),
+                points: card.points()?,
+                copies,
+            })
+        })
+        .collect()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    type Parsed<'a> = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed)?.to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed)?.to_string())
+    }
+
+    fn solve_both(input: &str) -> Result<(String, String), SolveError> {
+        let (part1, part2) = solve_both(input)?;
+        Ok((part1.to_string(), part2.to_string()))
+    }
+
+    fn merge(parsed: Vec<Self::Parsed<'_>>) -> Result<Self::Parsed<'_>, MergeError> {
+        Ok(merge(parsed))
+    }
+}
+
+aoc_common::register_day!(Day04);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn test_parse_input() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+
+        assert_eq!(
+            parsed_input[0],
+            Card::new(
+                1,
+                vec![41, 48, 83, 86, 17],
+                vec![83, 86, 6, 31, 17, 9, 48, 53]
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = parse_input("Card 1: 1 | 2\n\nCard 2: 1 2\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Line 3: Did not find a pipe in the input line: `Card 2: 1 2`"
+        );
+        assert!(matches!(
+            "Cad 1: 1 | 2".parse::<Card>(),
+            Err(AOCError::ParseCardIdErorr)
+        ));
+        assert!(matches!(
+            "C: 1 | 2".parse::<Card>(),
+            Err(AOCError::ParseCardIdErorr)
+        ));
+        assert!(matches!(
+            "Card 1: 1 | x".parse::<Card>(),
+            Err(AOCError::ParseNumberError(_))
+        ));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let cards: Vec<Card> = (1..=80)
+            .map(|id| Card::new(id, 0..80, (0..80).collect()))
+            .collect();
+        assert!(matches!(
+            process_part2(&cards),
+            Err(AOCError::OverflowError { id: 65 })
+        ));
+        assert_eq!(process_part2(&cards[..10]).unwrap(), 1023);
+        assert!(matches!(
+            process_part1(&cards),
+            Err(AOCError::OverflowError { id: 1 })
+        ));
+
+        // 2^63 points each, the sum overflows at the second card
+        let cards: Vec<Card> = (1..=2)
+            .map(|id| Card::new(id, 0..64, (0..64).collect()))
+            .collect();
+        assert_eq!(process_part1(&cards[..1]).unwrap(), 1 << 63);
+        assert!(matches!(
+            process_part1(&cards),
+            Err(AOCError::OverflowError { id: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_merge() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let merged = merge([parse_input(&input).unwrap(), parse_input(&input).unwrap()]);
+        assert_eq!(
+            merged.iter().map(|card| card.id).collect::<Vec<_>>(),
+            (1..=12).collect::<Vec<_>>()
+        );
+        assert_eq!(process_part1(&merged).unwrap(), 2 * 13);
+        assert_eq!(process_part2(&merged).unwrap(), 2 * 30);
+
+        // the last card of the first deck wins no copies of the second deck
+        let first = parse_input("Card 1: 1 | 2\nCard 2: 1 2 | 1 2").unwrap();
+        let second = parse_input("Card 1: 1 | 2\nCard 2: 1 | 2").unwrap();
+        let merged = merge([first, second]);
+        assert_eq!(process_part2(&merged).unwrap(), 4);
+        let merged = merge([merged, parse_input("Card 1: 1 | 1").unwrap()]);
+        assert_eq!(process_part2(&merged).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_process_part1() {
+        // aoc_common::setup_tracing();
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part1(&parsed_input);
+        assert_eq!(output.unwrap(), 13)
+    }
+
+    #[test]
+    fn test_scoring() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let cards = parse_input(&input).unwrap();
+        // the cards have 4, 2, 2, 1, 0 and 0 correct numbers
+        assert_eq!(process_part1_with(&cards, &Doubling).unwrap(), 13);
+        assert_eq!(process_part1_with(&cards, &Linear).unwrap(), 9);
+        assert_eq!(process_part1_with(&cards, &FirstMatchOnly).unwrap(), 4);
+        assert_eq!(process_part1_with(&cards, &|n: usize| n * n).unwrap(), 25);
+    }
+
+    #[test]
+    fn test_report() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let report = report(&parse_input(&input).unwrap()).unwrap();
+        assert_eq!(
+            report[0],
+            CardReport {
+                id: 1,
+                matching_numbers: vec![83, 86, 17, 48],
+                points: 8,
+                copies: 1,
+            }
+        );
+        assert_eq!(
+            report.iter().map(|card| card.copies).collect::<Vec<_>>(),
+            vec![1, 2, 4, 8, 14, 1]
+        );
+    }
+
+    #[test]
+    fn test_process_part2() {
+        // aoc_common::setup_tracing();
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part2(&parsed_input);
+        assert_eq!(output.unwrap(), 30)
+    }
+
+    #[test]
+    fn test_non_contiguous_ids() {
+        let cards = parse_input("Card 1: 1 | 1\nCard 3: 1 | 2").unwrap();
+        assert!(matches!(
+            process_part2(&cards),
+            Err(AOCError::NonContiguousIdsError { position: 2, id: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_solve_both() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        assert_eq!(solve_both(&input).unwrap(), (13, 30))
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let cards = parse_input(&input).unwrap();
+        let json = serde_json::to_string(&cards).unwrap();
+        assert_eq!(serde_json::from_str::<Vec<Card>>(&json).unwrap(), cards);
+
+        let card: Card = "Card 2: 1 2 | 2 3".parse().unwrap();
+        let value = serde_json::to_value(&card).unwrap();
+        assert_eq!(value["id"], 2);
+        assert_eq!(value["numbers"], serde_json::json!([2, 3]));
+        // the winning numbers are a set without a fixed order
+        assert_eq!(value["winning_numbers"].as_array().unwrap().len(), 2);
+    }
+}
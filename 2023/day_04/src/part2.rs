@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_04::Day04, part2);
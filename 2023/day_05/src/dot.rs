@@ -0,0 +1,34 @@
+use std::process;
+
+use aoc2023_day_05::Almanac;
+use aoc_common::input::InputArgs;
+use clap::Parser;
+
+/// Print the maps of the almanac as a Graphviz graph
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
+    /// Highlight the path of this seed
+    #[arg(long)]
+    seed: Option<usize>,
+}
+
+fn main() {
+    let args = Args::parse();
+    let input = args.input.source("input.txt").read().unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+    let dot = Almanac::parse(&input).and_then(|almanac| match args.seed {
+        Some(seed) => almanac.to_dot_with_seed(seed),
+        None => Ok(almanac.to_dot()),
+    });
+    match dot {
+        Ok(dot) => print!("{dot}"),
+        Err(e) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+    }
+}
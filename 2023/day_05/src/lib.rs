@@ -0,0 +1,1737 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    borrow::Cow,
+    collections::VecDeque,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use aoc_common::{
+    collections::HashMap, interval::IntervalSet, text::line_column, Answer, MergeError, NoProgress,
+    Progress, Solution, SolveError,
+};
+use core::{cmp::Ordering, fmt::Display, str::FromStr};
+use thiserror::Error;
+use winnow::{
+    ascii::{dec_uint, line_ending, multispace0, space0, space1},
+    combinator::{alt, cut_err, eof, not, peek, preceded, repeat, separated_pair, terminated},
+    error::{ContextError, ParseError, StrContext, StrContextValue},
+    token::take_till,
+    ModalResult, Parser,
+};
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum AOCError {
+    /// `line` and `column` are 1-based, the column counts characters.
+    #[error("Expected {expected} at line {line}, column {column}")]
+    SyntaxError {
+        line: usize,
+        column: usize,
+        expected: String,
+    },
+    #[error("Cannot merge almanacs with different maps")]
+    MergeMapsMismatchError,
+    #[error("There is more than one map from {from} to {to}")]
+    DuplicateMapError { from: String, to: String },
+    #[error("The maps contain a cycle through {0}")]
+    MapCycleError(String),
+    #[error("Unknown category `{0}`")]
+    UnknownCategoryError(String),
+    #[error("There is no chain of maps from {from} to {to}")]
+    UnreachableCategoryError { from: String, to: String },
+    #[error("The seed ranges need an even amount of numbers")]
+    OddSeedCountError,
+    #[error("There are no seeds")]
+    NoSeedsError,
+    #[error("Invalid id range `{0}`, expected an id or two ids like 55-67")]
+    IdRangeParseError(String),
+    #[error("The {length} ids starting at {start} exceed the largest id")]
+    IdOverflowError { start: usize, length: usize },
+}
+
+#[derive(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "AlmanacData")
+)]
+pub struct Almanac<'a> {
+    seeds: Vec<usize>,
+    maps: Vec<CategoryMap<'a>>,
+    /// Position of the map for each source and destination
+    #[cfg_attr(feature = "serde", serde(skip))]
+    index: HashMap<(Cow<'a, str>, Cow<'a, str>), usize>,
+    /// Positions of the maps for each source
+    #[cfg_attr(feature = "serde", serde(skip))]
+    graph: HashMap<Cow<'a, str>, Vec<usize>>,
+}
+
+/// The serialized fields of an almanac, the maps are indexed and checked
+/// again when deserializing.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct AlmanacData<'a> {
+    seeds: Vec<usize>,
+    maps: Vec<CategoryMap<'a>>,
+}
+
+#[cfg(feature = "serde")]
+impl<'a> TryFrom<AlmanacData<'a>> for Almanac<'a> {
+    type Error = AOCError;
+
+    fn try_from(data: AlmanacData<'a>) -> Result<Self, Self::Error> {
+        Almanac::new(data.seeds, data.maps)
+    }
+}
+
+/// Mapping from the ids of one category to the ids of another one. Ids that
+/// are not covered by any of the ranges keep their value. The category names
+/// borrow from the parsed input.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CategoryMap<'a> {
+    source: Cow<'a, str>,
+    destination: Cow<'a, str>,
+    ranges: Vec<Range>,
+}
+
+/// The `length` source ids beginning at `source_start` are mapped to the
+/// destination ids beginning at `destination_start`. Neither of them may
+/// exceed the largest id, which is checked when parsing.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "RangeData")
+)]
+pub struct Range {
+    destination_start: usize,
+    source_start: usize,
+    length: usize,
+}
+
+/// The fields of a range before checking that the ids don't overflow.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RangeData {
+    destination_start: usize,
+    source_start: usize,
+    length: usize,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RangeData> for Range {
+    type Error = AOCError;
+
+    fn try_from(data: RangeData) -> Result<Self, Self::Error> {
+        Range::try_new(data.destination_start, data.source_start, data.length)
+    }
+}
+
+/// The `length` consecutive ids beginning at `start`, which is the half-open
+/// interval `start..start + length`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SourceIdRange {
+    start: usize,
+    length: usize,
+}
+
+impl SourceIdRange {
+    /// Panics in debug builds if the ids exceed the largest id, use `try_new`
+    /// for unchecked input.
+    pub fn new(start: usize, length: usize) -> SourceIdRange {
+        debug_assert!(start.checked_add(length).is_some());
+        SourceIdRange { start, length }
+    }
+
+    pub fn try_new(start: usize, length: usize) -> Result<SourceIdRange, AOCError> {
+        match start.checked_add(length) {
+            Some(_) => Ok(SourceIdRange { start, length }),
+            None => Err(AOCError::IdOverflowError { start, length }),
+        }
+    }
+
+    /// The first id after the range.
+    pub fn end(&self) -> usize {
+        self.start + self.length
+    }
+
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    pub fn length(&self) -> usize {
+        self.length
+    }
+}
+
+impl From<core::ops::Range<usize>> for SourceIdRange {
+    fn from(ids: core::ops::Range<usize>) -> Self {
+        SourceIdRange::new(ids.start, ids.len())
+    }
+}
+
+impl From<SourceIdRange> for core::ops::Range<usize> {
+    fn from(ids: SourceIdRange) -> Self {
+        ids.start..ids.end()
+    }
+}
+
+impl From<usize> for SourceIdRange {
+    fn from(value: usize) -> Self {
+        SourceIdRange {
+            start: value,
+            length: 1,
+        }
+    }
+}
+
+/// Parse a single id or an inclusive range of ids like `55-67`.
+impl FromStr for SourceIdRange {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse = |id: &str| {
+            id.trim()
+                .parse::<usize>()
+                .map_err(|_| AOCError::IdRangeParseError(s.to_string()))
+        };
+        let (first, last) = match s.split_once('-') {
+            Some((first, last)) => (parse(first)?, parse(last)?),
+            None => (parse(s)?, parse(s)?),
+        };
+        if last < first {
+            return Err(AOCError::IdRangeParseError(s.to_string()));
+        }
+        // the end of the range needs to be representable as well
+        let length = (last - first)
+            .checked_add(1)
+            .ok_or(AOCError::IdRangeParseError(s.to_string()))?;
+        SourceIdRange::try_new(first, length)
+    }
+}
+
+/// The ids in the format accepted by `from_str`, empty ranges are shown as
+/// their start.
+impl Display for SourceIdRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.length <= 1 {
+            write!(f, "{}", self.start)
+        } else {
+            write!(f, "{}-{}", self.start, self.end() - 1)
+        }
+    }
+}
+
+impl Ord for SourceIdRange {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.start.cmp(&other.start)
+    }
+}
+
+impl PartialOrd for SourceIdRange {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// A category that is part of a cycle of the `maps`, if there is one.
+/// `graph` are the positions of the maps by their source.
+fn cycle_category(maps: &[CategoryMap], graph: &HashMap<Cow<str>, Vec<usize>>) -> Option<String> {
+    // Remove categories without incoming maps until none are left, the
+    // remaining ones are part of a cycle.
+    let mut incoming: HashMap<&str, usize> = HashMap::new();
+    for map in maps {
+        incoming.entry(&map.source).or_default();
+        *incoming.entry(&map.destination).or_default() += 1;
+    }
+    let mut queue: VecDeque<&str> = incoming
+        .iter()
+        .filter(|(_, count)| **count == 0)
+        .map(|(category, _)| *category)
+        .collect();
+    while let Some(category) = queue.pop_front() {
+        incoming.remove(category);
+        for &position in graph.get(category).into_iter().flatten() {
+            let destination = maps[position].destination.as_ref();
+            if let Some(count) = incoming.get_mut(destination) {
+                *count -= 1;
+                if *count == 0 {
+                    queue.push_back(destination);
+                }
+            }
+        }
+    }
+    incoming.keys().min().map(|category| category.to_string())
+}
+
+impl<'a> Almanac<'a> {
+    /// Index the maps, they must not contain a cycle.
+    fn new(seeds: Vec<usize>, maps: Vec<CategoryMap<'a>>) -> Result<Almanac<'a>, AOCError> {
+        maps.iter().try_for_each(CategoryMap::check)?;
+        let mut index = HashMap::new();
+        let mut graph: HashMap<Cow<'a, str>, Vec<usize>> = HashMap::new();
+        for (position, map) in maps.iter().enumerate() {
+            let key = (map.source.clone(), map.destination.clone());
+            if index.insert(key, position).is_some() {
+                return Err(AOCError::DuplicateMapError {
+                    from: map.source.to_string(),
+                    to: map.destination.to_string(),
+                });
+            }
+            graph.entry(map.source.clone()).or_default().push(position);
+        }
+
+        if let Some(category) = cycle_category(&maps, &graph) {
+            return Err(AOCError::MapCycleError(category));
+        }
+
+        Ok(Almanac {
+            seeds,
+            maps,
+            index,
+            graph,
+        })
+    }
+
+    /// The maps that convert from `source` to `destination`, in order.
+    fn path(&self, source: &str, destination: &str) -> Result<Vec<&CategoryMap<'a>>, AOCError> {
+        for category in [source, destination] {
+            if !self.graph.contains_key(category)
+                && !self.maps.iter().any(|map| map.destination == category)
+            {
+                return Err(AOCError::UnknownCategoryError(category.to_string()));
+            }
+        }
+        if source == destination {
+            return Ok(Vec::new());
+        }
+        let key = (Cow::Borrowed(source), Cow::Borrowed(destination));
+        if let Some(&position) = self.index.get(&key) {
+            return Ok(vec![&self.maps[position]]);
+        }
+
+        // breadth first search, remembering the map that reached each category
+        let mut reached_by: HashMap<&str, usize> = HashMap::new();
+        let mut queue = VecDeque::from([source]);
+        while let Some(category) = queue.pop_front() {
+            if category == destination {
+                let mut path = Vec::new();
+                let mut category = destination;
+                while category != source {
+                    let map = &self.maps[reached_by[category]];
+                    path.push(map);
+                    category = &map.source;
+                }
+                path.reverse();
+                return Ok(path);
+            }
+            for &position in self.graph.get(category).into_iter().flatten() {
+                let next = self.maps[position].destination.as_ref();
+                if next != source && !reached_by.contains_key(next) {
+                    reached_by.insert(next, position);
+                    queue.push_back(next);
+                }
+            }
+        }
+        Err(AOCError::UnreachableCategoryError {
+            from: source.to_string(),
+            to: destination.to_string(),
+        })
+    }
+
+    /// Convert the ids of the `source` category to the ids of the
+    /// `destination` category, walking the chain of maps in between. The
+    /// result is sorted, with adjacent ranges merged.
+    #[cfg_attr(
+        feature = "std",
+        tracing::instrument(level = "debug", skip(self, source_id))
+    )]
+    pub fn convert(
+        &self,
+        source_id: &[SourceIdRange],
+        source: &str,
+        destination: &str,
+    ) -> Result<Vec<SourceIdRange>, AOCError> {
+        let path = self.path(source, destination)?;
+        // normalized up front as well, for an empty path from a category to itself
+        Ok(path
+            .into_iter()
+            .fold(normalize(source_id.iter().copied()), |ids, map| {
+                map.calculate(&ids)
+            }))
+    }
+
+    /// Like `convert` for a set of ids, the result is normalized after every
+    /// map.
+    pub fn convert_set(
+        &self,
+        ids: &IntervalSet<usize>,
+        source: &str,
+        destination: &str,
+    ) -> Result<IntervalSet<usize>, AOCError> {
+        let ids: Vec<SourceIdRange> = ids.iter().map(|ids| ids.clone().into()).collect();
+        Ok(self
+            .convert(&ids, source, destination)?
+            .into_iter()
+            .map(core::ops::Range::from)
+            .collect())
+    }
+
+    /// The id of `seed` in every category from seed to location, to follow
+    /// where it ends up.
+    pub fn trace(&self, seed: usize) -> Result<Vec<(String, usize)>, AOCError> {
+        let mut trace = vec![("seed".to_string(), seed)];
+        let mut id = seed;
+        for map in self.path("seed", "location")? {
+            id = map.get(id);
+            trace.push((map.destination.to_string(), id));
+        }
+        Ok(trace)
+    }
+
+    /// Like `trace` for a range of seeds, which can be split up into several
+    /// ranges along the way.
+    pub fn trace_range(
+        &self,
+        seeds: SourceIdRange,
+    ) -> Result<Vec<(String, Vec<SourceIdRange>)>, AOCError> {
+        let mut trace = vec![("seed".to_string(), vec![seeds])];
+        let mut ids = vec![seeds];
+        for map in self.path("seed", "location")? {
+            ids = map.calculate(&ids);
+            trace.push((map.destination.to_string(), ids.clone()));
+        }
+        Ok(trace)
+    }
+
+    /// Convert the ids of the `source` category back to the ids of the
+    /// `destination` category that are mapped to them, walking the chain of
+    /// maps from `destination` to `source` backwards.
+    pub fn convert_reverse(
+        &self,
+        ids: &[SourceIdRange],
+        source: &str,
+        destination: &str,
+    ) -> Result<Vec<SourceIdRange>, AOCError> {
+        let path = self.path(destination, source)?;
+        Ok(path
+            .into_iter()
+            .rev()
+            .fold(normalize(ids.iter().copied()), |ids, map| {
+                map.preimage(&ids)
+            }))
+    }
+
+    /// Compose all maps from `source` to `destination` into a single map, so
+    /// that ids can be converted without walking the chain of maps each time.
+    pub fn composed(&self, source: &str, destination: &str) -> Result<CategoryMap<'a>, AOCError> {
+        let identity = CategoryMap {
+            source: Cow::Owned(source.to_string()),
+            destination: Cow::Owned(source.to_string()),
+            ranges: Vec::new(),
+        };
+        let path = self.path(source, destination)?;
+        Ok(path
+            .into_iter()
+            .fold(identity, |composed, map| composed.then(map)))
+    }
+
+    /// All categories, in the order in which they appear in the maps.
+    pub fn categories(&self) -> Vec<&str> {
+        let mut categories = Vec::new();
+        for map in &self.maps {
+            for category in [map.source.as_ref(), map.destination.as_ref()] {
+                if !categories.contains(&category) {
+                    categories.push(category);
+                }
+            }
+        }
+        categories
+    }
+
+    /// The maps as a Graphviz graph, with one node per category and the
+    /// ranges of each map as edge labels.
+    pub fn to_dot(&self) -> String {
+        self.dot(&[])
+    }
+
+    /// Like `to_dot`, additionally highlighting the path of `seed` and its id
+    /// in every category.
+    pub fn to_dot_with_seed(&self, seed: usize) -> Result<String, AOCError> {
+        Ok(self.dot(&self.trace(seed)?))
+    }
+
+    fn dot(&self, trace: &[(String, usize)]) -> String {
+        let id_in = |category: &str| {
+            trace
+                .iter()
+                .find(|(traced, _)| traced == category)
+                .map(|(_, id)| *id)
+        };
+        let mut dot = String::from("digraph almanac {\n    rankdir=LR;\n");
+        for category in self.categories() {
+            let name = escape(category);
+            match id_in(category) {
+                Some(id) => dot.push_str(&format!(
+                    "    \"{name}\" [label=\"{name}\\n{id}\", color=red, fontcolor=red];\n"
+                )),
+                None => dot.push_str(&format!("    \"{name}\";\n")),
+            }
+        }
+        for map in &self.maps {
+            let label: Vec<String> = map
+                .ranges
+                .iter()
+                .map(|range| {
+                    format!(
+                        "{}..{} -> {}..{}",
+                        range.source_start,
+                        range.source_end(),
+                        range.destination_start,
+                        range.destination_end()
+                    )
+                })
+                .collect();
+            let on_path = id_in(&map.source).is_some() && id_in(&map.destination).is_some();
+            dot.push_str(&format!(
+                "    \"{}\" -> \"{}\" [label=\"{}\"{}];\n",
+                escape(&map.source),
+                escape(&map.destination),
+                label.join("\\n"),
+                if on_path { ", color=red" } else { "" }
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Merge the seeds of another almanac into this one. Both almanacs need to
+    /// contain the same maps.
+    pub fn merge(&mut self, other: Almanac<'a>) -> Result<(), AOCError> {
+        if self.maps != other.maps {
+            return Err(AOCError::MergeMapsMismatchError);
+        }
+        self.seeds.extend(other.seeds);
+        Ok(())
+    }
+}
+
+impl Extend<usize> for Almanac<'_> {
+    fn extend<T: IntoIterator<Item = usize>>(&mut self, seeds: T) {
+        self.seeds.extend(seeds)
+    }
+}
+
+impl<'a> CategoryMap<'a> {
+    fn check(&self) -> Result<(), AOCError> {
+        self.ranges.iter().try_for_each(Range::check)
+    }
+
+    pub fn into_owned(self) -> CategoryMap<'static> {
+        CategoryMap {
+            source: Cow::Owned(self.source.into_owned()),
+            destination: Cow::Owned(self.destination.into_owned()),
+            ranges: self.ranges,
+        }
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn destination(&self) -> &str {
+        &self.destination
+    }
+
+    pub fn ranges(&self) -> &[Range] {
+        &self.ranges
+    }
+
+    /// The destination id of a single source id.
+    pub fn get(&self, id: usize) -> usize {
+        match self.ranges.iter().find(|range| range.contains(id)) {
+            Some(range) => id - range.source_start + range.destination_start,
+            None => id,
+        }
+    }
+
+    /// The map that first applies this map and then `next`.
+    fn then(&self, next: &CategoryMap<'a>) -> CategoryMap<'a> {
+        // ids covered by a range of this map continue with its destination ids
+        let mut ranges: Vec<Range> = self
+            .ranges
+            .iter()
+            .flat_map(|range| {
+                let destination_ids = SourceIdRange::new(range.destination_start, range.length);
+                next.split(&destination_ids)
+                    .into_iter()
+                    .map(move |(destination_id, next_range)| {
+                        let source_start =
+                            destination_id.start - range.destination_start + range.source_start;
+                        let destination_id =
+                            next_range.map_or(destination_id, |r| r.translate(&destination_id));
+                        Range::new(destination_id.start, source_start, destination_id.length)
+                    })
+            })
+            .collect();
+        // ids that this map keeps as they are are only changed by `next`
+        for next_range in &next.ranges {
+            let source_ids = SourceIdRange::new(next_range.source_start, next_range.length);
+            for (source_id, range) in self.split(&source_ids) {
+                if range.is_none() {
+                    let destination_id = next_range.translate(&source_id);
+                    ranges.push(Range::new(
+                        destination_id.start,
+                        source_id.start,
+                        source_id.length,
+                    ));
+                }
+            }
+        }
+        CategoryMap {
+            source: self.source.clone(),
+            destination: next.destination.clone(),
+            ranges,
+        }
+    }
+
+    /// The destination ids of `source_ids`, sorted and with overlapping and
+    /// adjacent ranges merged so that they don't fragment over several maps.
+    fn calculate(&self, source_ids: &[SourceIdRange]) -> Vec<SourceIdRange> {
+        normalize(
+            source_ids
+                .iter()
+                .flat_map(|source_id| self.calculate_single(source_id)),
+        )
+    }
+
+    fn calculate_single(&self, source_id: &SourceIdRange) -> Vec<SourceIdRange> {
+        self.split(source_id)
+            .into_iter()
+            .map(|(source_id, range)| match range {
+                // calculate destination id for ranges where we have overlap
+                Some(range) => range.translate(&source_id),
+                // ranges that are not matched keep the same source ids
+                None => source_id,
+            })
+            .collect()
+    }
+
+    /// All source ids that are mapped to one of `destination_ids`.
+    fn preimage(&self, destination_ids: &[SourceIdRange]) -> Vec<SourceIdRange> {
+        let source_ids = destination_ids.iter().flat_map(|destination_id| {
+            // ids that are moved by a range
+            let moved = self.ranges.iter().filter_map(|range| {
+                let inverse = Range::new(range.source_start, range.destination_start, range.length);
+                inverse
+                    .overlap(destination_id)
+                    .matching
+                    .map(|matching| inverse.translate(&matching))
+            });
+            // ids that are not covered by any range are mapped to themselves
+            let kept = self
+                .split(destination_id)
+                .into_iter()
+                .filter(|(_, range)| range.is_none())
+                .map(|(id, _)| id);
+            moved.chain(kept).collect::<Vec<_>>()
+        });
+        normalize(source_ids)
+    }
+
+    /// Split `source_id` into the parts covered by the individual ranges and
+    /// the parts that are not covered by any range.
+    fn split(&self, source_id: &SourceIdRange) -> Vec<(SourceIdRange, Option<&Range>)> {
+        // calculate the overlap betwen the source_id and each range
+        let mut remaining = IntervalSet::from(core::ops::Range::from(*source_id));
+        let mut parts = Vec::new();
+        for range in self.ranges.iter() {
+            let covered = range.source_ids();
+            for matching in remaining.intersection(&covered).iter() {
+                parts.push((matching.clone().into(), Some(range)));
+            }
+            remaining = remaining.difference(&covered);
+        }
+        parts.extend(remaining.iter().map(|ids| (ids.clone().into(), None)));
+        parts
+    }
+}
+
+/// Escape a name for a quoted Graphviz string.
+fn escape(name: &str) -> String {
+    name.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Sort the ranges and merge the ones that overlap or are adjacent.
+fn normalize(ids: impl IntoIterator<Item = SourceIdRange>) -> Vec<SourceIdRange> {
+    ids.into_iter()
+        .map(core::ops::Range::from)
+        .collect::<IntervalSet<_>>()
+        .iter()
+        .map(|ids| ids.clone().into())
+        .collect()
+}
+
+#[derive(Debug, PartialEq)]
+struct RangeOverlap {
+    matching: Option<SourceIdRange>,
+    remaining: Vec<SourceIdRange>,
+}
+
+impl Range {
+    /// Panics in debug builds if the ids exceed the largest id, use
+    /// `try_new` for unchecked input.
+    fn new(destination_start: usize, source_start: usize, length: usize) -> Range {
+        debug_assert!(Range::try_new(destination_start, source_start, length).is_ok());
+        Range {
+            destination_start,
+            source_start,
+            length,
+        }
+    }
+
+    fn try_new(
+        destination_start: usize,
+        source_start: usize,
+        length: usize,
+    ) -> Result<Range, AOCError> {
+        SourceIdRange::try_new(source_start, length)?;
+        SourceIdRange::try_new(destination_start, length)?;
+        Ok(Range {
+            destination_start,
+            source_start,
+            length,
+        })
+    }
+
+    /// Parsed ranges are checked afterwards, the parser has no way to
+    /// report why a value is rejected without `std`.
+    fn check(&self) -> Result<(), AOCError> {
+        Range::try_new(self.destination_start, self.source_start, self.length).map(drop)
+    }
+
+    /// The first source id after the range.
+    fn source_end(&self) -> usize {
+        self.source_start + self.length
+    }
+
+    /// The first destination id after the range.
+    fn destination_end(&self) -> usize {
+        self.destination_start + self.length
+    }
+
+    fn contains(&self, id: usize) -> bool {
+        self.source_start <= id && id < self.source_end()
+    }
+
+    /// The source ids covered by the range.
+    fn source_ids(&self) -> IntervalSet<usize> {
+        IntervalSet::from(self.source_start..self.source_end())
+    }
+
+    fn overlap(&self, source_id: &SourceIdRange) -> RangeOverlap {
+        let source_ids = IntervalSet::from(core::ops::Range::from(*source_id));
+        let covered = self.source_ids();
+        RangeOverlap {
+            matching: source_ids
+                .intersection(&covered)
+                .iter()
+                .next()
+                .map(|ids| ids.clone().into()),
+            remaining: source_ids
+                .difference(&covered)
+                .iter()
+                .map(|ids| ids.clone().into())
+                .collect(),
+        }
+    }
+
+    /// The destination ids of `source_id`, which needs to be covered by the
+    /// range. Both ends stay between the start and the end of the destination
+    /// ids, so the calculation can't overflow.
+    fn translate(&self, source_id: &SourceIdRange) -> SourceIdRange {
+        debug_assert!(self.source_start <= source_id.start && source_id.end() <= self.source_end());
+        let start = source_id.start - self.source_start + self.destination_start;
+        SourceIdRange::new(start, source_id.length)
+    }
+}
+
+/// Whether `range.overlap(source)` partitions `source`: the matching and the
+/// remaining ids are non-empty pieces that cover every id of `source` exactly
+/// once, only the matching ones are source ids of `range`, and translating
+/// them keeps their number.
+pub fn check_partition(range: &Range, source: &SourceIdRange) -> bool {
+    let RangeOverlap {
+        matching,
+        remaining,
+    } = range.overlap(source);
+    let mut pieces: Vec<SourceIdRange> = remaining.iter().copied().chain(matching).collect();
+    pieces.sort();
+    let mut next = source.start;
+    for piece in &pieces {
+        if piece.length == 0 || piece.start != next {
+            return false;
+        }
+        next = piece.end();
+    }
+    let inside =
+        |ids: &SourceIdRange| range.source_start <= ids.start && ids.end() <= range.source_end();
+    let outside = |ids: &SourceIdRange| {
+        range.length == 0 || ids.end() <= range.source_start || range.source_end() <= ids.start
+    };
+    next == source.end()
+        && matching
+            .iter()
+            .all(|ids| inside(ids) && range.translate(ids).length == ids.length)
+        && remaining.iter().all(outside)
+}
+
+fn expected(description: &'static str) -> StrContext {
+    StrContext::Expected(StrContextValue::Description(description))
+}
+
+/// The error of a failed parser.
+fn parse_error(input: &str, error: ParseError<&str, ContextError>) -> AOCError {
+    let expected = error
+        .inner()
+        .context()
+        .find_map(|context| match context {
+            StrContext::Expected(value) => Some(value.to_string()),
+            _ => None,
+        })
+        .unwrap_or_else(|| "an almanac".to_string());
+    let (line, column) = line_column(input, error.offset());
+    AOCError::SyntaxError {
+        line,
+        column,
+        expected,
+    }
+}
+
+fn number(input: &mut &str) -> ModalResult<usize> {
+    dec_uint.context(expected("a number")).parse_next(input)
+}
+
+/// `seeds: 79 14 55 13`, to the end of the line.
+fn seeds(input: &mut &str) -> ModalResult<Vec<usize>> {
+    preceded(
+        "seeds:".context(expected("`seeds:`")),
+        terminated(
+            repeat(0.., preceded(space1, number)),
+            (
+                space0,
+                peek(alt((line_ending, eof))).context(expected("a number")),
+            ),
+        ),
+    )
+    .parse_next(input)
+}
+
+fn category<'a>(input: &mut &'a str) -> ModalResult<&'a str> {
+    take_till(1.., ['-', ' ', '\r', '\n'])
+        .context(expected("a category"))
+        .parse_next(input)
+}
+
+/// `<destination start> <source start> <length>`. The ids are not checked
+/// for overflows, see `Range::check`.
+fn range(input: &mut &str) -> ModalResult<Range> {
+    (
+        preceded(space0, number),
+        cut_err((
+            preceded(space1, number).context(expected("a number")),
+            terminated(preceded(space1, number), space0).context(expected("a number")),
+        )),
+    )
+        .map(|(destination_start, (source_start, length))| Range {
+            destination_start,
+            source_start,
+            length,
+        })
+        .parse_next(input)
+}
+
+/// `seed-to-soil map:` followed by one range per line.
+fn category_map<'a>(input: &mut &'a str) -> ModalResult<CategoryMap<'a>> {
+    let header = terminated(
+        separated_pair(category, "-to-".context(expected("`-to-`")), category),
+        " map:".context(expected("` map:`")),
+    );
+    // every line until the next blank line is a range
+    let ranges = repeat(
+        0..,
+        preceded((line_ending, not(alt((line_ending, eof)))), cut_err(range)),
+    );
+    (header, ranges)
+        .map(|((source, destination), ranges)| CategoryMap {
+            source: Cow::Borrowed(source),
+            destination: Cow::Borrowed(destination),
+            ranges,
+        })
+        .parse_next(input)
+}
+
+/// The seeds and the maps, separated by blank lines.
+fn almanac<'a>(input: &mut &'a str) -> ModalResult<(Vec<usize>, Vec<CategoryMap<'a>>)> {
+    let maps = repeat(
+        0..,
+        preceded(
+            (line_ending, line_ending, peek(not((multispace0, eof)))),
+            cut_err(category_map),
+        ),
+    );
+    let end = (
+        multispace0,
+        eof.context(expected("a blank line before the map")),
+    );
+    (preceded(multispace0, seeds), terminated(maps, end)).parse_next(input)
+}
+
+impl<'a> Almanac<'a> {
+    /// Parse an almanac without copying the category names, see `FromStr`
+    /// for an almanac that owns them.
+    pub fn parse(input: &'a str) -> Result<Almanac<'a>, AOCError> {
+        let (seeds, maps) = almanac.parse(input).map_err(|e| parse_error(input, e))?;
+        Almanac::new(seeds, maps)
+    }
+}
+
+impl FromStr for Almanac<'static> {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (seeds, maps) = almanac.parse(s).map_err(|e| parse_error(s, e))?;
+        Almanac::new(
+            seeds,
+            maps.into_iter().map(CategoryMap::into_owned).collect(),
+        )
+    }
+}
+
+impl FromStr for CategoryMap<'static> {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = terminated(
+            category_map,
+            (multispace0, eof.context(expected("a range"))),
+        )
+        .parse(s)
+        .map_err(|e| parse_error(s, e))?;
+        map.check()?;
+        Ok(map.into_owned())
+    }
+}
+
+impl FromStr for Range {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let range = terminated(
+            range,
+            (multispace0, eof.context(expected("the end of the range"))),
+        )
+        .parse(s)
+        .map_err(|e| parse_error(s, e))?;
+        range.check()?;
+        Ok(range)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Almanac<'_>, AOCError> {
+    Almanac::parse(input)
+}
+
+pub fn process_part1(almanac: &Almanac) -> Result<usize, AOCError> {
+    let seed_to_location = almanac.composed("seed", "location")?;
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| seed_to_location.get(seed))
+        .min()
+        .ok_or(AOCError::NoSeedsError)
+}
+
+pub fn process_part2(almanac: &Almanac) -> Result<usize, AOCError> {
+    process_part2_with_progress(almanac, &NoProgress)
+}
+
+/// Like `process_part2`, reporting each converted seed range as one step.
+pub fn process_part2_with_progress(
+    almanac: &Almanac,
+    progress: &dyn Progress,
+) -> Result<usize, AOCError> {
+    let seed_ranges = seed_ranges(almanac)?;
+    let seed_to_location = almanac.composed("seed", "location")?;
+    progress.set_total(seed_ranges.len() as u64);
+    let lowest_location = |seed_range: &SourceIdRange| {
+        let location = seed_to_location
+            .calculate(&[*seed_range])
+            .into_iter()
+            .map(core::ops::Range::from)
+            .collect::<IntervalSet<_>>()
+            .first();
+        progress.advance(1);
+        location
+    };
+    // seed ranges are independent of each other, only their minima are combined
+    #[cfg(feature = "parallel")]
+    let lowest_location = {
+        use rayon::prelude::*;
+        seed_ranges.par_iter().filter_map(lowest_location).min()
+    };
+    #[cfg(not(feature = "parallel"))]
+    let lowest_location = seed_ranges.iter().filter_map(lowest_location).min();
+    lowest_location.ok_or(AOCError::NoSeedsError)
+}
+
+/// Part 2 the other way around: check the locations from 0 upward and return
+/// the first one that belongs to one of the seed ranges.
+pub fn process_part2_reverse(almanac: &Almanac) -> Result<usize, AOCError> {
+    let seeds: IntervalSet<usize> = seed_ranges(almanac)?
+        .into_iter()
+        .map(core::ops::Range::from)
+        .collect();
+    if seeds.is_empty() {
+        return Err(AOCError::NoSeedsError);
+    }
+    let seed_to_location = almanac.composed("seed", "location")?;
+    (0..)
+        .find(|&location| {
+            seed_to_location
+                .preimage(&[location.into()])
+                .iter()
+                .any(|seed| seeds.contains(seed.start))
+        })
+        .ok_or(AOCError::NoSeedsError)
+}
+
+fn seed_ranges(almanac: &Almanac) -> Result<Vec<SourceIdRange>, AOCError> {
+    almanac
+        .seeds
+        .chunks(2)
+        .map(|chunk| match chunk {
+            &[start, length] => SourceIdRange::try_new(start, length),
+            _ => Err(AOCError::OddSeedCountError),
+        })
+        .collect()
+}
+
+pub fn solve_both(input: &str) -> Result<(usize, usize), AOCError> {
+    let almanac = parse_input(input)?;
+    Ok((process_part1(&almanac)?, process_part2(&almanac)?))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Parsed<'a> = Almanac<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed)?.to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed)?.to_string())
+    }
+
+    fn part2_with_progress(parsed: &Self::Parsed<'_>, progress: &dyn Progress) -> Answer {
+        Ok(process_part2_with_progress(parsed, progress)?.to_string())
+    }
+
+    fn merge(parsed: Vec<Self::Parsed<'_>>) -> Result<Self::Parsed<'_>, MergeError> {
+        let mut almanacs = parsed.into_iter();
+        let mut almanac = almanacs.next().ok_or(MergeError::Unsupported)?;
+        for other in almanacs {
+            almanac
+                .merge(other)
+                .map_err(|e| MergeError::Incompatible(e.to_string()))?;
+        }
+        Ok(almanac)
+    }
+}
+
+aoc_common::register_day!(Day05);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use proptest::prelude::*;
+    use std::fs;
+
+    #[test]
+    fn test_parse_input() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let almanac = parse_input(&input).unwrap();
+
+        assert_eq!(almanac.seeds, vec![79, 14, 55, 13]);
+
+        let first_map = almanac.maps.first().unwrap();
+        assert_eq!(first_map.source, "seed");
+        assert_eq!(first_map.destination, "soil");
+        assert_eq!(
+            first_map.ranges,
+            vec![Range::new(50, 98, 2), Range::new(52, 50, 48)]
+        );
+    }
+
+    #[test]
+    fn test_parse_trailing_blank_lines() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let almanac = parse_input(&input).unwrap();
+        for end in ["\n\n", "\r\n\r\n", "\n\n\n"] {
+            assert_eq!(parse_input(&format!("{input}{end}")).unwrap(), almanac);
+        }
+        assert_eq!(parse_input("seeds: 1 2\n\n").unwrap().seeds, vec![1, 2]);
+        assert_eq!("1 2 3\n".parse(), Ok(Range::new(1, 2, 3)));
+    }
+
+    #[test]
+    fn test_almanac_merge() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let mut almanac = parse_input(&input).unwrap();
+        almanac.merge(parse_input(&input).unwrap()).unwrap();
+        assert_eq!(almanac.seeds, vec![79, 14, 55, 13, 79, 14, 55, 13]);
+
+        almanac.extend([82, 1]);
+        assert_eq!(almanac.seeds.len(), 10);
+        assert_eq!(process_part2(&almanac).unwrap(), 46);
+
+        let other = parse_input("seeds: 1 2\n\nseed-to-soil map:\n1 2 3").unwrap();
+        assert!(matches!(
+            almanac.merge(other),
+            Err(AOCError::MergeMapsMismatchError)
+        ));
+    }
+
+    #[test]
+    fn test_category_map_calculate() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let almanac = parse_input(&input).unwrap();
+        let first_map = almanac.maps.first().unwrap();
+        assert_eq!(first_map.calculate(&[98.into()]), vec![50.into()]);
+        assert_eq!(first_map.calculate(&[99.into()]), vec![51.into()]);
+        assert_eq!(first_map.calculate(&[100.into()]), vec![100.into()]);
+        assert_eq!(first_map.calculate(&[79.into()]), vec![81.into()]);
+        assert_eq!(first_map.calculate(&[14.into()]), vec![14.into()]);
+        assert_eq!(first_map.calculate(&[55.into()]), vec![57.into()]);
+        assert_eq!(first_map.calculate(&[13.into()]), vec![13.into()]);
+    }
+
+    #[test]
+    fn test_almanac_convert() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let almanac = parse_input(&input).unwrap();
+
+        assert_eq!(
+            almanac.convert(&[79.into()], "seed", "location").unwrap(),
+            vec![82.into()]
+        );
+        assert_eq!(
+            almanac.convert(&[14.into()], "seed", "location").unwrap(),
+            vec![43.into()]
+        );
+        assert_eq!(
+            almanac.convert(&[55.into()], "seed", "location").unwrap(),
+            vec![86.into()]
+        );
+        assert_eq!(
+            almanac.convert(&[13.into()], "seed", "location").unwrap(),
+            vec![35.into()]
+        );
+    }
+
+    #[test]
+    fn test_almanac_composed() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let almanac = parse_input(&input).unwrap();
+
+        let seed_to_location = almanac.composed("seed", "location").unwrap();
+        assert_eq!(seed_to_location.source(), "seed");
+        assert_eq!(seed_to_location.destination(), "location");
+        for seed in 0..120 {
+            assert_eq!(
+                almanac.convert(&[seed.into()], "seed", "location").unwrap(),
+                vec![seed_to_location.get(seed).into()]
+            );
+        }
+
+        let light_to_light = almanac.composed("light", "light").unwrap();
+        assert!(light_to_light.ranges().is_empty());
+        assert_eq!(light_to_light.get(77), 77);
+        assert!(matches!(
+            almanac.composed("location", "seed"),
+            Err(AOCError::UnreachableCategoryError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_almanac_convert_reverse() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let almanac = parse_input(&input).unwrap();
+
+        assert_eq!(
+            almanac
+                .convert_reverse(&[82.into()], "location", "seed")
+                .unwrap(),
+            vec![79.into()]
+        );
+        for seed in 0..120 {
+            let location = almanac.convert(&[seed.into()], "seed", "location").unwrap();
+            let seeds = almanac
+                .convert_reverse(&location, "location", "seed")
+                .unwrap();
+            assert!(seeds
+                .iter()
+                .any(|ids| ids.start() <= seed && seed < ids.start() + ids.length()));
+        }
+        // 98 and 99 are moved to 50 and 51, 50 is moved to 52
+        let seed_to_soil = almanac.maps.first().unwrap();
+        let mut seeds = seed_to_soil.preimage(&[SourceIdRange::new(50, 3)]);
+        seeds.sort();
+        assert_eq!(seeds, vec![50.into(), SourceIdRange::new(98, 2)]);
+        // ids that are not covered by any range are their own preimage
+        assert_eq!(seed_to_soil.preimage(&[120.into()]), vec![120.into()]);
+
+        assert!(matches!(
+            almanac.convert_reverse(&[1.into()], "seed", "location"),
+            Err(AOCError::UnreachableCategoryError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_almanac_convert_normalizes() {
+        let ranges: String = (0..10).map(|i| format!("\n{} {i} 1", i + 100)).collect();
+        let almanac: Almanac = format!("seeds: 0 10\n\na-to-b map:{ranges}")
+            .parse()
+            .unwrap();
+        assert_eq!(
+            almanac
+                .convert(&[SourceIdRange::new(0, 12)], "a", "b")
+                .unwrap(),
+            vec![SourceIdRange::new(10, 2), SourceIdRange::new(100, 10)]
+        );
+
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let almanac = parse_input(&input).unwrap();
+        let seeds: IntervalSet<usize> = [79..93, 55..68].into_iter().collect();
+        let locations = almanac.convert_set(&seeds, "seed", "location").unwrap();
+        assert_eq!(locations.first(), Some(46));
+        assert_eq!(
+            locations.iter().map(|ids| ids.len()).sum::<usize>(),
+            14 + 13
+        );
+        for pair in locations.intervals().windows(2) {
+            assert!(pair[0].end < pair[1].start);
+        }
+
+        // a category converted to itself is normalized like any other
+        let ids = [SourceIdRange::new(10, 5), SourceIdRange::new(0, 12)];
+        let normalized = vec![SourceIdRange::new(0, 15)];
+        assert_eq!(almanac.convert(&ids, "soil", "soil").unwrap(), normalized);
+        assert_eq!(
+            almanac.convert_reverse(&ids, "soil", "soil").unwrap(),
+            normalized
+        );
+        let identity = almanac.composed("soil", "soil").unwrap();
+        assert_eq!(identity.calculate(&ids), normalized);
+    }
+
+    #[test]
+    fn test_almanac_trace() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let almanac = parse_input(&input).unwrap();
+
+        let trace = almanac.trace(79).unwrap();
+        let expected = [
+            ("seed", 79),
+            ("soil", 81),
+            ("fertilizer", 81),
+            ("water", 81),
+            ("light", 74),
+            ("temperature", 78),
+            ("humidity", 78),
+            ("location", 82),
+        ];
+        assert_eq!(
+            trace,
+            expected.map(|(category, id)| (category.to_string(), id))
+        );
+
+        let trace = almanac.trace_range(SourceIdRange::new(79, 14)).unwrap();
+        assert_eq!(trace.len(), 8);
+        assert_eq!(
+            trace[1],
+            ("soil".to_string(), vec![SourceIdRange::new(81, 14)])
+        );
+        let (category, locations) = trace.last().unwrap();
+        assert_eq!(category, "location");
+        assert_eq!(locations.iter().map(|l| l.length()).sum::<usize>(), 14);
+        assert_eq!(locations.iter().map(|l| l.start()).min(), Some(46));
+    }
+
+    #[test]
+    fn test_almanac_categories() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let almanac = parse_input(&input).unwrap();
+        assert_eq!(
+            almanac.categories(),
+            vec![
+                "seed",
+                "soil",
+                "fertilizer",
+                "water",
+                "light",
+                "temperature",
+                "humidity",
+                "location"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_source_id_range_from_str() {
+        assert_eq!("55".parse::<SourceIdRange>().unwrap(), 55.into());
+        let range: SourceIdRange = "55-67".parse().unwrap();
+        assert_eq!(range, SourceIdRange::new(55, 13));
+        assert_eq!(range.to_string(), "55-67");
+        assert_eq!(SourceIdRange::from(3).to_string(), "3");
+        for invalid in ["", "a", "5-", "7-5"] {
+            assert!(matches!(
+                invalid.parse::<SourceIdRange>(),
+                Err(AOCError::IdRangeParseError(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_almanac_to_dot() {
+        let almanac = parse_input(
+            "seeds: 1\n\nseed-to-soil map:\n50 98 2\n52 50 48\n\nsoil-to-\"x\" map:\n0 0 1",
+        )
+        .unwrap();
+        assert_eq!(
+            almanac.to_dot(),
+            r#"digraph almanac {
+    rankdir=LR;
+    "seed";
+    "soil";
+    "\"x\"";
+    "seed" -> "soil" [label="98..100 -> 50..52\n50..98 -> 52..100"];
+    "soil" -> "\"x\"" [label="0..1 -> 0..1"];
+}
+"#
+        );
+
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let almanac = parse_input(&input).unwrap();
+        let dot = almanac.to_dot_with_seed(79).unwrap();
+        assert!(dot.contains(r#"    "light" [label="light\n74", color=red, fontcolor=red];"#));
+        assert!(dot.contains(
+            r#"    "seed" -> "soil" [label="98..100 -> 50..52\n50..98 -> 52..100", color=red];"#
+        ));
+        assert_eq!(dot.matches(", color=red];").count(), 7);
+    }
+
+    #[test]
+    fn test_process_part2_reverse() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let almanac = parse_input(&input).unwrap();
+        assert_eq!(process_part2_reverse(&almanac).unwrap(), 46);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let syntax_error = |line, column, expected: &str| {
+            Err(AOCError::SyntaxError {
+                line,
+                column,
+                expected: expected.to_string(),
+            })
+        };
+        let tests = [
+            (
+                "seeds: 1\n\nx-to-y map:\n1 2",
+                syntax_error(4, 4, "a number"),
+            ),
+            ("a = (b, c)", syntax_error(1, 1, "`seeds:`")),
+            ("seeds: 1 x", syntax_error(1, 10, "a number")),
+            (
+                "seeds: 1\nx-to-y map:",
+                syntax_error(2, 1, "a blank line before the map"),
+            ),
+            (
+                "seeds: 1\n\nseed-to-location",
+                syntax_error(3, 17, "` map:`"),
+            ),
+            (
+                "seeds: 1\n\nseed to soil map:",
+                syntax_error(3, 5, "`-to-`"),
+            ),
+            ("seeds: 1\n\n-to-y map:", syntax_error(3, 1, "a category")),
+            (
+                "seeds: 1\n\nx-to-y map:\n1 2 3\n4 x 6",
+                syntax_error(5, 3, "a number"),
+            ),
+        ];
+        for (input, expected) in tests {
+            assert_eq!(parse_input(input), expected, "{input}");
+        }
+        assert_eq!(
+            Day05::parse("seeds: 1\n\nseed-to-location")
+                .unwrap_err()
+                .to_string(),
+            "Expected ` map:` at line 3, column 17"
+        );
+    }
+
+    #[test]
+    fn test_parse_borrowed() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let almanac = parse_input(&input).unwrap();
+        assert!(matches!(almanac.maps[0].source, Cow::Borrowed("seed")));
+        let owned: Almanac<'static> = input.parse().unwrap();
+        assert_eq!(owned, almanac);
+        assert!(matches!(owned.maps[0].source, Cow::Owned(_)));
+        assert_eq!(
+            "seed-to-soil map:\n50 98 2\n52 50 48\n"
+                .parse::<CategoryMap>()
+                .unwrap(),
+            almanac.maps[0]
+        );
+    }
+
+    #[test]
+    fn test_almanac_graph_errors() {
+        let almanac = parse_input("seeds: 1\n\nseed-to-soil map:\n1 2 3").unwrap();
+        assert!(matches!(
+            almanac.convert(&[1.into()], "seed", "location"),
+            Err(AOCError::UnknownCategoryError(category)) if category == "location"
+        ));
+        assert_eq!(
+            process_part1(&almanac).unwrap_err().to_string(),
+            "Unknown category `location`"
+        );
+        assert!(matches!(
+            process_part2(&almanac),
+            Err(AOCError::OddSeedCountError)
+        ));
+
+        let cyclic = "seeds: 1\n\na-to-b map:\n1 2 3\n\nb-to-c map:\n1 2 3\n\nc-to-b map:\n1 2 3";
+        assert!(matches!(
+            cyclic.parse::<Almanac>(),
+            Err(AOCError::MapCycleError(category)) if category == "b"
+        ));
+        let duplicate = "seeds: 1\n\na-to-b map:\n1 2 3\n\na-to-b map:\n1 2 3";
+        assert!(matches!(
+            duplicate.parse::<Almanac>(),
+            Err(AOCError::DuplicateMapError { .. })
+        ));
+
+        let branching =
+            "seeds: 1\n\na-to-b map:\n1 2 3\n\na-to-c map:\n1 2 3\n\nc-to-d map:\n1 2 3";
+        let almanac: Almanac = branching.parse().unwrap();
+        assert_eq!(almanac.path("a", "d").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_range_overlap() {
+        let source_id = SourceIdRange::new(10, 10); // 10 - 19
+                                                    // overlap checks
+        let range_1 = Range::new(0, 4, 3); // 4 - 6
+        let range_2 = Range::new(0, 8, 4); // 8 - 11
+        let range_3 = Range::new(0, 14, 3); // 14 -  16
+        let range_4 = Range::new(0, 18, 3); // 18 -  20
+        let range_5 = Range::new(0, 25, 5); // 25 -  29
+        let range_10 = Range::new(0, 5, 20);
+        // edge cases
+        let range_6 = Range::new(0, 8, 3); // 8 - 10
+        let range_7 = Range::new(0, 10, 2); // 10 - 11
+        let range_8 = Range::new(0, 18, 2); // 18 - 19
+        let range_9 = Range::new(0, 19, 2); // 19 - 20
+
+        assert_eq!(
+            range_1.overlap(&source_id),
+            RangeOverlap {
+                matching: None,
+                remaining: vec![source_id]
+            }
+        );
+        assert_eq!(
+            range_2.overlap(&source_id),
+            RangeOverlap {
+                matching: Some(SourceIdRange::new(10, 2)), // 10 - 11
+                remaining: vec![SourceIdRange::new(12, 8)]
+            }
+        );
+        assert_eq!(
+            range_3.overlap(&source_id),
+            RangeOverlap {
+                matching: Some(SourceIdRange::new(14, 3)),
+                remaining: vec![SourceIdRange::new(10, 4), SourceIdRange::new(17, 3)]
+            }
+        );
+        assert_eq!(
+            range_4.overlap(&source_id),
+            RangeOverlap {
+                matching: Some(SourceIdRange::new(18, 2)),
+                remaining: vec![SourceIdRange::new(10, 8)]
+            }
+        );
+        assert_eq!(
+            range_5.overlap(&source_id),
+            RangeOverlap {
+                matching: None,
+                remaining: vec![source_id]
+            }
+        );
+        assert_eq!(
+            range_6.overlap(&source_id),
+            RangeOverlap {
+                matching: Some(SourceIdRange::new(10, 1)),
+                remaining: vec![SourceIdRange::new(11, 9)]
+            }
+        );
+        assert_eq!(
+            range_7.overlap(&source_id),
+            RangeOverlap {
+                matching: Some(SourceIdRange::new(10, 2)),
+                remaining: vec![SourceIdRange::new(12, 8)]
+            }
+        );
+        assert_eq!(
+            range_8.overlap(&source_id),
+            RangeOverlap {
+                matching: Some(SourceIdRange::new(18, 2)),
+                remaining: vec![SourceIdRange::new(10, 8)]
+            }
+        );
+        assert_eq!(
+            range_9.overlap(&source_id),
+            RangeOverlap {
+                matching: Some(SourceIdRange::new(19, 1)),
+                remaining: vec![SourceIdRange::new(10, 9)]
+            }
+        );
+        assert_eq!(
+            range_10.overlap(&source_id),
+            RangeOverlap {
+                matching: Some(source_id),
+                remaining: vec![]
+            }
+        );
+    }
+
+    #[test]
+    fn test_range_boundaries() {
+        // ids 10 - 19
+        let source_id = SourceIdRange::new(10, 10);
+        // ranges ending right before and starting right after the ids
+        for range in [Range::new(0, 5, 5), Range::new(0, 20, 5)] {
+            assert_eq!(
+                range.overlap(&source_id),
+                RangeOverlap {
+                    matching: None,
+                    remaining: vec![source_id]
+                }
+            );
+        }
+        // ranges sharing exactly the first and the last id
+        assert_eq!(
+            Range::new(0, 5, 6).overlap(&source_id),
+            RangeOverlap {
+                matching: Some(10.into()),
+                remaining: vec![SourceIdRange::new(11, 9)]
+            }
+        );
+        assert_eq!(
+            Range::new(0, 19, 6).overlap(&source_id),
+            RangeOverlap {
+                matching: Some(19.into()),
+                remaining: vec![SourceIdRange::new(10, 9)]
+            }
+        );
+        // exactly the same ids
+        assert_eq!(
+            Range::new(0, 10, 10).overlap(&source_id),
+            RangeOverlap {
+                matching: Some(source_id),
+                remaining: vec![]
+            }
+        );
+        // empty ranges never match
+        assert_eq!(Range::new(0, 12, 0).overlap(&source_id).matching, None);
+
+        // ranges at the end of the id space
+        let max = usize::MAX;
+        let range = Range::new(max - 10, 0, 10);
+        assert_eq!(
+            range.translate(&SourceIdRange::new(5, 5)),
+            SourceIdRange::new(max - 5, 5)
+        );
+        assert_eq!(
+            Range::new(0, max - 10, 10).translate(&(max - 1).into()),
+            9.into()
+        );
+    }
+
+    /// Ids that fit into the id space, mostly at small values so that they
+    /// overlap often, some anywhere including the largest ids.
+    fn source_id_range() -> impl Strategy<Value = SourceIdRange> {
+        prop_oneof![
+            3 => (0..64_usize, 0..32_usize),
+            1 => any::<usize>().prop_flat_map(|start| (Just(start), 0..=usize::MAX - start)),
+            1 => (0..32_usize).prop_map(|length| (usize::MAX - length, length)),
+        ]
+        .prop_map(|(start, length)| SourceIdRange::new(start, length))
+    }
+
+    fn range() -> impl Strategy<Value = Range> {
+        (source_id_range(), source_id_range()).prop_map(|(source, destination)| {
+            let length = source.length.min(destination.length);
+            Range::new(destination.start, source.start, length)
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn test_overlap_partitions(range in range(), source_id in source_id_range()) {
+            prop_assert!(check_partition(&range, &source_id));
+        }
+
+        #[test]
+        fn test_translate_preserves_length(range in range(), offset in 0..32_usize, length in 0..32_usize) {
+            // ids inside the source ids of the range
+            let offset = offset.min(range.length);
+            let length = length.min(range.length - offset);
+            let source_id = SourceIdRange::new(range.source_start + offset, length);
+            let destination_id = range.translate(&source_id);
+            prop_assert_eq!(destination_id.length, length);
+            prop_assert_eq!(destination_id.start, range.destination_start + offset);
+        }
+    }
+
+    #[test]
+    fn test_overflow_errors() {
+        let max = usize::MAX;
+        for range in [
+            format!("0 {max} 1"),
+            format!("{max} 0 1"),
+            format!("0 1 {max}"),
+        ] {
+            assert!(matches!(
+                range.parse::<Range>(),
+                Err(AOCError::IdOverflowError { .. })
+            ));
+        }
+        assert!(format!("0 {} 1", max - 1).parse::<Range>().is_ok());
+
+        let input = format!("seeds: {max} 1\n\nseed-to-location map:\n0 1 2");
+        let almanac = parse_input(&input).unwrap();
+        assert!(matches!(
+            process_part2(&almanac),
+            Err(AOCError::IdOverflowError { start, length: 1 }) if start == max
+        ));
+        assert!(matches!(
+            format!("0-{max}").parse::<SourceIdRange>(),
+            Err(AOCError::IdRangeParseError(_))
+        ));
+        assert!(matches!(
+            format!("{max}").parse::<SourceIdRange>(),
+            Err(AOCError::IdOverflowError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_process_part1() {
+        // aoc_common::setup_tracing();
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part1(&parsed_input).unwrap();
+        assert_eq!(output, 35)
+    }
+
+    #[test]
+    fn test_process_part2() {
+        // aoc_common::setup_tracing();
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part2(&parsed_input).unwrap();
+        assert_eq!(output, 46)
+    }
+
+    #[test]
+    fn test_process_part2_progress() {
+        use std::sync::atomic::{AtomicU64, Ordering};
+
+        #[derive(Default)]
+        struct Counter {
+            total: AtomicU64,
+            done: AtomicU64,
+        }
+
+        impl Progress for Counter {
+            fn set_total(&self, total: u64) {
+                self.total.store(total, Ordering::Relaxed)
+            }
+
+            fn advance(&self, delta: u64) {
+                self.done.fetch_add(delta, Ordering::Relaxed);
+            }
+        }
+
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let counter = Counter::default();
+        assert_eq!(
+            process_part2_with_progress(&parsed_input, &counter).unwrap(),
+            46
+        );
+        assert_eq!(counter.total.load(Ordering::Relaxed), 2);
+        assert_eq!(counter.done.load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn test_solve_both() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        assert_eq!(solve_both(&input).unwrap(), (35, 46))
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let almanac = parse_input(&input).unwrap();
+        let json = serde_json::to_string(&almanac).unwrap();
+        assert!(json.starts_with(r#"{"seeds":[79,14,55,13],"maps":[{"source":"seed","#));
+        assert!(!json.contains("index"));
+        let deserialized: Almanac = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, almanac);
+        assert_eq!(deserialized.trace(79).unwrap(), almanac.trace(79).unwrap());
+
+        let map = r#"{"source":"a","destination":"b","ranges":[]}"#;
+        let error =
+            serde_json::from_str::<Almanac>(&format!(r#"{{"seeds":[],"maps":[{map},{map}]}}"#));
+        assert!(error
+            .unwrap_err()
+            .to_string()
+            .contains("more than one map from a to b"));
+        let range = format!(
+            r#"{{"destination_start":0,"source_start":{},"length":2}}"#,
+            usize::MAX
+        );
+        assert!(serde_json::from_str::<Range>(&range).is_err());
+    }
+}
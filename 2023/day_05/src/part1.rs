@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_05::Day05, part1);
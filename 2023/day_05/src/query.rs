@@ -0,0 +1,48 @@
+use std::{fmt::Display, process};
+
+use aoc2023_day_05::{AOCError, Almanac, SourceIdRange};
+use aoc_common::input::InputArgs;
+use clap::Parser;
+
+/// Convert ids between two categories of the almanac
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    input: InputArgs,
+    /// Category of the given ids
+    #[arg(long, default_value = "seed")]
+    from: String,
+    /// Category to convert the ids to
+    #[arg(long, default_value = "location")]
+    to: String,
+    /// Ids or inclusive ranges of ids like 55-67
+    #[arg(required = true, value_parser = clap::value_parser!(SourceIdRange))]
+    ids: Vec<SourceIdRange>,
+}
+
+fn exit_with(error: impl Display) -> ! {
+    eprintln!("{error}");
+    process::exit(1);
+}
+
+fn main() {
+    let args = Args::parse();
+    let input = args
+        .input
+        .source("input.txt")
+        .read()
+        .unwrap_or_else(|e| exit_with(e));
+    let almanac = Almanac::parse(&input).unwrap_or_else(|e| exit_with(e));
+    match almanac.convert(&args.ids, &args.from, &args.to) {
+        Ok(ids) => {
+            for ids in ids {
+                println!("{ids}");
+            }
+        }
+        Err(e @ AOCError::UnknownCategoryError(_)) => exit_with(format!(
+            "{e}, the categories are {}",
+            almanac.categories().join(", ")
+        )),
+        Err(e) => exit_with(e),
+    }
+}
@@ -0,0 +1,247 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use aoc_common::{Answer, Solution, SolveError};
+use core::str::FromStr;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AOCError {
+    #[error("Could not find the line starting with `{0}`")]
+    MissingLineError(&'static str),
+    #[error("Could not parse number: `{0}`")]
+    ParseNumberError(String),
+    #[error("There are {times} times but {distances} distances")]
+    LengthMismatchError { times: usize, distances: usize },
+    #[error("The combined race is too long")]
+    OverflowError,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Race {
+    time: u64,
+    distance: u64,
+}
+
+impl Race {
+    pub fn new(time: u64, distance: u64) -> Race {
+        Race { time, distance }
+    }
+
+    pub fn time(&self) -> u64 {
+        self.time
+    }
+
+    /// The record distance of the race.
+    pub fn distance(&self) -> u64 {
+        self.distance
+    }
+
+    /// Distance travelled when holding the button for `hold` milliseconds.
+    fn travelled(&self, hold: u64) -> u128 {
+        hold as u128 * (self.time - hold) as u128
+    }
+
+    /// The number of hold times that beat the record distance.
+    ///
+    /// The travelled distance `hold * (time - hold)` grows until half of the
+    /// race time and is symmetric around it, so it is enough to search for
+    /// the shortest winning hold time.
+    pub fn ways_to_win(&self) -> u64 {
+        let record = self.distance as u128;
+        let (mut low, mut high) = (0, self.time / 2);
+        if self.travelled(high) <= record {
+            return 0;
+        }
+        // the shortest winning hold time is in (low, high]
+        while high - low > 1 {
+            let middle = low + (high - low) / 2;
+            if self.travelled(middle) > record {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+        self.time - 2 * high + 1
+    }
+}
+
+/// The races as they appear on the sheet of paper.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Races(Vec<Race>);
+
+impl Races {
+    pub fn races(&self) -> &[Race] {
+        &self.0
+    }
+
+    /// The single race that you get when ignoring the spaces between the
+    /// numbers.
+    pub fn combined(&self) -> Result<Race, AOCError> {
+        Ok(Race::new(
+            concat(self.0.iter().map(|race| race.time))?,
+            concat(self.0.iter().map(|race| race.distance))?,
+        ))
+    }
+}
+
+/// Write the numbers after each other, `[7, 15, 30]` becomes `71530`.
+fn concat(mut numbers: impl Iterator<Item = u64>) -> Result<u64, AOCError> {
+    numbers.try_fold(0_u64, |combined, number| {
+        let digits = number.checked_ilog10().unwrap_or(0) + 1;
+        10_u64
+            .checked_pow(digits)
+            .and_then(|shift| combined.checked_mul(shift))
+            .and_then(|combined| combined.checked_add(number))
+            .ok_or(AOCError::OverflowError)
+    })
+}
+
+impl FromStr for Races {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let numbers = |label: &'static str| -> Result<Vec<u64>, AOCError> {
+            s.lines()
+                .find_map(|line| line.trim().strip_prefix(label))
+                .ok_or(AOCError::MissingLineError(label))?
+                .split_whitespace()
+                .map(|number| {
+                    number
+                        .parse()
+                        .map_err(|_| AOCError::ParseNumberError(number.to_string()))
+                })
+                .collect()
+        };
+        let times = numbers("Time:")?;
+        let distances = numbers("Distance:")?;
+        if times.len() != distances.len() {
+            return Err(AOCError::LengthMismatchError {
+                times: times.len(),
+                distances: distances.len(),
+            });
+        }
+        Ok(Races(
+            times
+                .into_iter()
+                .zip(distances)
+                .map(|(time, distance)| Race::new(time, distance))
+                .collect(),
+        ))
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Races, AOCError> {
+    input.parse()
+}
+
+pub fn process_part1(races: &Races) -> u64 {
+    races.races().iter().map(Race::ways_to_win).product()
+}
+
+pub fn process_part2(races: &Races) -> Result<u64, AOCError> {
+    Ok(races.combined()?.ways_to_win())
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    type Parsed<'a> = Races;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed).to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed)?.to_string())
+    }
+}
+
+aoc_common::register_day!(Day06);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn test_parse_input() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let races = parse_input(&input).unwrap();
+        assert_eq!(
+            races.races(),
+            &[Race::new(7, 9), Race::new(15, 40), Race::new(30, 200)]
+        );
+        assert_eq!(races.combined().unwrap(), Race::new(71530, 940200));
+        assert_eq!(concat([10, 0, 5].into_iter()).unwrap(), 1005);
+        assert!(matches!(
+            concat([u64::MAX, 1].into_iter()),
+            Err(AOCError::OverflowError)
+        ));
+
+        assert!(matches!(
+            parse_input("Time: 1 2\nDistance: 3"),
+            Err(AOCError::LengthMismatchError {
+                times: 2,
+                distances: 1
+            })
+        ));
+        assert!(matches!(
+            parse_input("Time: 1"),
+            Err(AOCError::MissingLineError("Distance:"))
+        ));
+        assert!(matches!(
+            parse_input("Time: 1 x\nDistance: 1 2"),
+            Err(AOCError::ParseNumberError(_))
+        ));
+    }
+
+    #[test]
+    fn test_ways_to_win() {
+        assert_eq!(Race::new(7, 9).ways_to_win(), 4);
+        assert_eq!(Race::new(15, 40).ways_to_win(), 8);
+        assert_eq!(Race::new(30, 200).ways_to_win(), 9);
+        // the record can't be beaten, or only exactly in the middle
+        assert_eq!(Race::new(4, 4).ways_to_win(), 0);
+        assert_eq!(Race::new(4, 3).ways_to_win(), 1);
+        assert_eq!(Race::new(0, 0).ways_to_win(), 0);
+        // compare with trying every hold time
+        for time in 0..40 {
+            for distance in 0..200 {
+                let race = Race::new(time, distance);
+                let brute_force = (0..=time)
+                    .filter(|&hold| race.travelled(hold) > distance as u128)
+                    .count() as u64;
+                assert_eq!(race.ways_to_win(), brute_force, "{race:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_process_part1() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part1(&parsed_input);
+        assert_eq!(output, 288)
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part2(&parsed_input).unwrap();
+        assert_eq!(output, 71503)
+    }
+}
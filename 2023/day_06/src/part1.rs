@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_06::Day06, part1);
@@ -0,0 +1,306 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use aoc_common::{Answer, Solution, SolveError};
+use core::str::FromStr;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AOCError {
+    #[error("Unknown card `{0}`")]
+    CardError(char),
+    #[error("A hand needs 5 cards, found {0}")]
+    HandSizeError(usize),
+    #[error("Did not find a bid")]
+    MissingBidError,
+    #[error("Could not parse number: `{0}`")]
+    ParseNumberError(String),
+    #[error("Line {line}: {error}: `{content}`")]
+    InvalidLineError {
+        line: usize,
+        content: String,
+        error: Box<AOCError>,
+    },
+}
+
+/// A card, ordered by its strength.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Card(u8);
+
+impl Card {
+    /// A jack that is used as a joker, which is the weakest card.
+    pub const JOKER: Card = Card(1);
+    pub const JACK: Card = Card(11);
+}
+
+impl TryFrom<char> for Card {
+    type Error = AOCError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        let strength = match value {
+            '2'..='9' => value as u8 - b'0',
+            'T' => 10,
+            'J' => 11,
+            'Q' => 12,
+            'K' => 13,
+            'A' => 14,
+            _ => return Err(AOCError::CardError(value)),
+        };
+        Ok(Card(strength))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandType {
+    /// The best type that can be made from the cards, jokers are counted as
+    /// the card that there are most of.
+    fn of(cards: &[Card; 5]) -> HandType {
+        let mut counts = [0_u8; 15];
+        let mut jokers = 0;
+        for card in cards {
+            if *card == Card::JOKER {
+                jokers += 1;
+            } else {
+                counts[card.0 as usize] += 1;
+            }
+        }
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        match (counts[0] + jokers, counts[1]) {
+            (5, _) => HandType::FiveOfAKind,
+            (4, _) => HandType::FourOfAKind,
+            (3, 2) => HandType::FullHouse,
+            (3, _) => HandType::ThreeOfAKind,
+            (2, 2) => HandType::TwoPair,
+            (2, _) => HandType::OnePair,
+            _ => HandType::HighCard,
+        }
+    }
+}
+
+/// A hand of cards. Hands are ordered by their type first, and then by the
+/// strength of their cards from the first to the last one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Hand {
+    hand_type: HandType,
+    cards: [Card; 5],
+}
+
+impl Hand {
+    pub fn new(cards: [Card; 5]) -> Hand {
+        Hand {
+            hand_type: HandType::of(&cards),
+            cards,
+        }
+    }
+
+    pub fn hand_type(&self) -> HandType {
+        self.hand_type
+    }
+
+    pub fn cards(&self) -> &[Card; 5] {
+        &self.cards
+    }
+
+    /// The same hand with every jack used as a joker.
+    pub fn with_jokers(&self) -> Hand {
+        Hand::new(self.cards.map(|card| {
+            if card == Card::JACK {
+                Card::JOKER
+            } else {
+                card
+            }
+        }))
+    }
+}
+
+impl FromStr for Hand {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cards = s
+            .chars()
+            .map(Card::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let cards: [Card; 5] = cards
+            .try_into()
+            .map_err(|cards: Vec<Card>| AOCError::HandSizeError(cards.len()))?;
+        Ok(Hand::new(cards))
+    }
+}
+
+/// A hand together with its bid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Play {
+    pub hand: Hand,
+    pub bid: u64,
+}
+
+impl FromStr for Play {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (hand, bid) = s.trim().split_once(' ').ok_or(AOCError::MissingBidError)?;
+        let bid = bid
+            .trim()
+            .parse()
+            .map_err(|_| AOCError::ParseNumberError(bid.to_string()))?;
+        Ok(Play {
+            hand: hand.parse()?,
+            bid,
+        })
+    }
+}
+
+/// Parse one hand and bid per line, empty lines are skipped.
+pub fn parse_input(input: &str) -> Result<Vec<Play>, AOCError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            line.parse().map_err(|error| AOCError::InvalidLineError {
+                line: idx + 1,
+                content: line.to_string(),
+                error: Box::new(error),
+            })
+        })
+        .collect()
+}
+
+/// The sum of the bids multiplied by the rank of their hand, the weakest hand
+/// has rank 1.
+pub fn total_winnings(plays: impl IntoIterator<Item = Play>) -> u64 {
+    let mut plays: Vec<Play> = plays.into_iter().collect();
+    plays.sort_by_key(|play| play.hand);
+    plays
+        .iter()
+        .zip(1..)
+        .map(|(play, rank)| play.bid * rank)
+        .sum()
+}
+
+pub fn process_part1(plays: &[Play]) -> u64 {
+    total_winnings(plays.iter().copied())
+}
+
+pub fn process_part2(plays: &[Play]) -> u64 {
+    total_winnings(plays.iter().map(|play| Play {
+        hand: play.hand.with_jokers(),
+        bid: play.bid,
+    }))
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+
+    type Parsed<'a> = Vec<Play>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed).to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed).to_string())
+    }
+}
+
+aoc_common::register_day!(Day07);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    fn hand(cards: &str) -> Hand {
+        cards.parse().unwrap()
+    }
+
+    #[test]
+    fn test_hand_type() {
+        assert_eq!(hand("AAAAA").hand_type(), HandType::FiveOfAKind);
+        assert_eq!(hand("AA8AA").hand_type(), HandType::FourOfAKind);
+        assert_eq!(hand("23332").hand_type(), HandType::FullHouse);
+        assert_eq!(hand("TTT98").hand_type(), HandType::ThreeOfAKind);
+        assert_eq!(hand("23432").hand_type(), HandType::TwoPair);
+        assert_eq!(hand("A23A4").hand_type(), HandType::OnePair);
+        assert_eq!(hand("23456").hand_type(), HandType::HighCard);
+
+        assert_eq!(
+            hand("QJJQ2").with_jokers().hand_type(),
+            HandType::FourOfAKind
+        );
+        assert_eq!(
+            hand("JJJJJ").with_jokers().hand_type(),
+            HandType::FiveOfAKind
+        );
+        assert_eq!(hand("2345J").with_jokers().hand_type(), HandType::OnePair);
+        assert_eq!(hand("2244J").with_jokers().hand_type(), HandType::FullHouse);
+    }
+
+    #[test]
+    fn test_hand_ordering() {
+        assert!(hand("33332") > hand("2AAAA"));
+        assert!(hand("77888") > hand("77788"));
+        assert!(hand("KK677") > hand("KTJJT"));
+        assert!(hand("T55J5") < hand("QQQJA"));
+        // jokers make the hand stronger but are the weakest card
+        assert!(hand("JKKK2").with_jokers() < hand("QQQQ2").with_jokers());
+        assert!(hand("JKKK2").with_jokers() > hand("JKKK2"));
+        assert!(hand("J2345").with_jokers() < hand("22345"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(matches!(
+            "2345".parse::<Hand>(),
+            Err(AOCError::HandSizeError(4))
+        ));
+        assert!(matches!(
+            "2345X".parse::<Hand>(),
+            Err(AOCError::CardError('X'))
+        ));
+        assert_eq!(
+            parse_input("32T3K 765\nT55J5").unwrap_err().to_string(),
+            "Line 2: Did not find a bid: `T55J5`"
+        );
+    }
+
+    #[test]
+    fn test_process_part1() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part1(&parsed_input);
+        assert_eq!(output, 6440)
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part2(&parsed_input);
+        assert_eq!(output, 5905)
+    }
+}
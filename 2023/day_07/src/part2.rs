@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_07::Day07, part2);
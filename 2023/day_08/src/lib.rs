@@ -0,0 +1,374 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use aoc_common::{collections::HashMap, math::checked_lcm, Answer, Solution, SolveError};
+use core::str::FromStr;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AOCError {
+    #[error("Could not find the instructions")]
+    MissingInstructionsError,
+    #[error("Unknown instruction `{0}`")]
+    InstructionError(char),
+    #[error("Could not parse node: `{0}`")]
+    NodeParseError(String),
+    #[error("Node `{0}` is defined more than once")]
+    DuplicateNodeError(String),
+    #[error("Unknown node `{0}`")]
+    UnknownNodeError(String),
+    #[error("`{to}` can't be reached from `{from}`")]
+    UnreachableError { from: String, to: String },
+    #[error("The path from `{0}` does not reach an end node periodically")]
+    NotPeriodicError(String),
+    #[error("The number of steps overflows")]
+    OverflowError,
+    #[error("There are no start nodes")]
+    NoStartNodesError,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = AOCError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(AOCError::InstructionError(value)),
+        }
+    }
+}
+
+/// The instructions and the nodes of the map. Nodes are referred to by their
+/// position in `names`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Network {
+    instructions: Vec<Direction>,
+    names: Vec<String>,
+    /// Left and right neighbor of each node
+    neighbors: Vec<(usize, usize)>,
+    index: HashMap<String, usize>,
+}
+
+/// The path of a ghost starting at `start`. After `cycle_start` steps the
+/// ghost is in a loop of `cycle_length` steps, so that after
+/// `cycle_start + cycle_length` steps it is at the same node and instruction
+/// as after `cycle_start` steps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleAnalysis {
+    pub start: String,
+    pub cycle_start: usize,
+    pub cycle_length: usize,
+    /// The steps before entering the loop the second time at which the ghost
+    /// is at a node ending with `Z`.
+    pub end_steps: Vec<usize>,
+}
+
+impl CycleAnalysis {
+    /// The number of steps `p` if the ghost is at an end node exactly after
+    /// `p`, `2 * p`, `3 * p`, ... steps. This is what makes it possible to
+    /// combine the ghosts with the least common multiple.
+    pub fn period(&self) -> Option<usize> {
+        let period = *self.end_steps.first()?;
+        let end = self.cycle_start + self.cycle_length;
+        let periodic = self.cycle_start <= period
+            && self.cycle_length.is_multiple_of(period)
+            && self
+                .end_steps
+                .iter()
+                .copied()
+                .eq((period..end).step_by(period));
+        periodic.then_some(period)
+    }
+}
+
+impl Network {
+    pub fn instructions(&self) -> &[Direction] {
+        &self.instructions
+    }
+
+    fn node(&self, name: &str) -> Result<usize, AOCError> {
+        self.index
+            .get(name)
+            .copied()
+            .ok_or_else(|| AOCError::UnknownNodeError(name.to_string()))
+    }
+
+    fn next(&self, node: usize, step: usize) -> usize {
+        let (left, right) = self.neighbors[node];
+        match self.instructions[step % self.instructions.len()] {
+            Direction::Left => left,
+            Direction::Right => right,
+        }
+    }
+
+    /// The number of steps from `from` to `to`.
+    pub fn steps(&self, from: &str, to: &str) -> Result<usize, AOCError> {
+        let (start, end) = (self.node(from)?, self.node(to)?);
+        // after visiting every node at every instruction the path repeats
+        let limit = self.names.len() * self.instructions.len();
+        let mut node = start;
+        for step in 0..=limit {
+            if node == end {
+                return Ok(step);
+            }
+            node = self.next(node, step);
+        }
+        Err(AOCError::UnreachableError {
+            from: from.to_string(),
+            to: to.to_string(),
+        })
+    }
+
+    /// Follow the path from `start` until it repeats.
+    pub fn analyze(&self, start: &str) -> Result<CycleAnalysis, AOCError> {
+        let mut node = self.node(start)?;
+        let mut visited = HashMap::new();
+        let mut end_steps = Vec::new();
+        for step in 0.. {
+            let state = (node, step % self.instructions.len());
+            if let Some(cycle_start) = visited.insert(state, step) {
+                return Ok(CycleAnalysis {
+                    start: start.to_string(),
+                    cycle_start,
+                    cycle_length: step - cycle_start,
+                    end_steps,
+                });
+            }
+            if step > 0 && self.names[node].ends_with('Z') {
+                end_steps.push(step);
+            }
+            node = self.next(node, step);
+        }
+        unreachable!("the number of states is finite")
+    }
+
+    /// The analysis of every node ending with `A`.
+    pub fn cycles(&self) -> Result<Vec<CycleAnalysis>, AOCError> {
+        self.names
+            .iter()
+            .filter(|name| name.ends_with('A'))
+            .map(|name| self.analyze(name))
+            .collect()
+    }
+}
+
+impl FromStr for Network {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines().map(str::trim).filter(|line| !line.is_empty());
+        let instructions = lines
+            .next()
+            .ok_or(AOCError::MissingInstructionsError)?
+            .chars()
+            .map(Direction::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        if instructions.is_empty() {
+            return Err(AOCError::MissingInstructionsError);
+        }
+
+        let mut nodes = Vec::new();
+        for line in lines {
+            let node = line
+                .split_once(" = ")
+                .and_then(|(name, neighbors)| {
+                    let (left, right) = neighbors
+                        .strip_prefix('(')?
+                        .strip_suffix(')')?
+                        .split_once(", ")?;
+                    Some((name, left, right))
+                })
+                .ok_or_else(|| AOCError::NodeParseError(line.to_string()))?;
+            nodes.push(node);
+        }
+
+        let mut index = HashMap::new();
+        for (position, (name, _, _)) in nodes.iter().enumerate() {
+            if index.insert(name.to_string(), position).is_some() {
+                return Err(AOCError::DuplicateNodeError(name.to_string()));
+            }
+        }
+        let lookup = |name: &str| {
+            index
+                .get(name)
+                .copied()
+                .ok_or_else(|| AOCError::UnknownNodeError(name.to_string()))
+        };
+        let neighbors = nodes
+            .iter()
+            .map(|(_, left, right)| Ok((lookup(left)?, lookup(right)?)))
+            .collect::<Result<Vec<_>, AOCError>>()?;
+
+        Ok(Network {
+            instructions,
+            names: nodes.iter().map(|(name, _, _)| name.to_string()).collect(),
+            neighbors,
+            index,
+        })
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Network, AOCError> {
+    input.parse()
+}
+
+pub fn process_part1(network: &Network) -> Result<usize, AOCError> {
+    network.steps("AAA", "ZZZ")
+}
+
+/// The number of steps until all ghosts are at an end node at the same time,
+/// which is the least common multiple of their periods.
+pub fn process_part2(network: &Network) -> Result<usize, AOCError> {
+    let cycles = network.cycles()?;
+    if cycles.is_empty() {
+        return Err(AOCError::NoStartNodesError);
+    }
+    cycles.iter().try_fold(1, |steps, cycle| {
+        let period = cycle
+            .period()
+            .ok_or_else(|| AOCError::NotPeriodicError(cycle.start.clone()))?;
+        checked_lcm(steps, period).ok_or(AOCError::OverflowError)
+    })
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+
+    type Parsed<'a> = Network;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed)?.to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed)?.to_string())
+    }
+}
+
+aoc_common::register_day!(Day08);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn test_parse_input() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let network = parse_input(&input).unwrap();
+        assert_eq!(
+            network.instructions(),
+            &[Direction::Left, Direction::Left, Direction::Right]
+        );
+        assert_eq!(network.names, vec!["AAA", "BBB", "ZZZ"]);
+        assert_eq!(network.neighbors, vec![(1, 1), (0, 2), (2, 2)]);
+
+        assert!(matches!(
+            parse_input("LX\n\nAAA = (AAA, AAA)"),
+            Err(AOCError::InstructionError('X'))
+        ));
+        assert!(matches!(
+            parse_input("L\n\nAAA = (AAA, BBB)"),
+            Err(AOCError::UnknownNodeError(name)) if name == "BBB"
+        ));
+        assert!(matches!(
+            parse_input("L\n\nAAA = AAA, AAA"),
+            Err(AOCError::NodeParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_steps() {
+        let network = parse_input(
+            "RL\n\nAAA = (BBB, CCC)\nBBB = (DDD, EEE)\nCCC = (ZZZ, GGG)\nDDD = (DDD, DDD)\nEEE = (EEE, EEE)\nGGG = (GGG, GGG)\nZZZ = (ZZZ, ZZZ)",
+        )
+        .unwrap();
+        assert_eq!(network.steps("AAA", "ZZZ").unwrap(), 2);
+        assert_eq!(network.steps("AAA", "AAA").unwrap(), 0);
+        assert!(matches!(
+            network.steps("ZZZ", "AAA"),
+            Err(AOCError::UnreachableError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_cycles() {
+        let input = fs::read_to_string("input_test_part2.txt").expect("Could not read the file");
+        let network = parse_input(&input).unwrap();
+        let cycles = network.cycles().unwrap();
+        assert_eq!(
+            cycles,
+            vec![
+                CycleAnalysis {
+                    start: "11A".to_string(),
+                    cycle_start: 1,
+                    cycle_length: 2,
+                    end_steps: vec![2],
+                },
+                CycleAnalysis {
+                    start: "22A".to_string(),
+                    cycle_start: 1,
+                    cycle_length: 6,
+                    end_steps: vec![3, 6],
+                },
+            ]
+        );
+        assert_eq!(cycles[0].period(), Some(2));
+        assert_eq!(cycles[1].period(), Some(3));
+
+        // the end node is reached after 6 steps, but then at every step
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let cycle = parse_input(&input).unwrap().analyze("AAA").unwrap();
+        assert_eq!((cycle.cycle_start, cycle.cycle_length), (6, 3));
+        assert_eq!(cycle.end_steps, vec![6, 7, 8]);
+        assert_eq!(cycle.period(), None);
+    }
+
+    #[test]
+    fn test_process_part1() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part1(&parsed_input).unwrap();
+        assert_eq!(output, 6)
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input = fs::read_to_string("input_test_part2.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part2(&parsed_input).unwrap();
+        assert_eq!(output, 6);
+
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        assert!(matches!(
+            process_part2(&parsed_input),
+            Err(AOCError::NotPeriodicError(start)) if start == "AAA"
+        ));
+
+        let parsed_input = parse_input("L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+        assert!(matches!(
+            process_part2(&parsed_input),
+            Err(AOCError::NoStartNodesError)
+        ));
+    }
+}
@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_08::Day08, part2);
@@ -0,0 +1,134 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use aoc_common::{Answer, Solution, SolveError};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AOCError {
+    #[error("Could not parse number: `{0}`")]
+    ParseNumberError(String),
+    #[error("Line {line}: {error}: `{content}`")]
+    InvalidLineError {
+        line: usize,
+        content: String,
+        error: Box<AOCError>,
+    },
+}
+
+/// Parse one history of values per line, empty lines are skipped.
+pub fn parse_input(input: &str) -> Result<Vec<Vec<i64>>, AOCError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            line.split_whitespace()
+                .map(|value| {
+                    value
+                        .parse()
+                        .map_err(|_| AOCError::ParseNumberError(value.to_string()))
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|error| AOCError::InvalidLineError {
+                    line: idx + 1,
+                    content: line.to_string(),
+                    error: Box::new(error),
+                })
+        })
+        .collect()
+}
+
+/// The values before the first and after the last value of the sequence.
+///
+/// The sequences of differences are built until all differences are zero.
+/// The next value is the sum of the last values of all sequences, the
+/// previous value is their first values with alternating signs.
+pub fn extrapolate(seq: &[i64]) -> (i64, i64) {
+    let mut differences = seq.to_vec();
+    let (mut next, mut previous, mut sign) = (0, 0, 1);
+    while differences.iter().any(|&d| d != 0) {
+        next += differences[differences.len() - 1];
+        previous += sign * differences[0];
+        sign = -sign;
+        differences = differences.windows(2).map(|w| w[1] - w[0]).collect();
+    }
+    (next, previous)
+}
+
+pub fn process_part1(histories: &[Vec<i64>]) -> i64 {
+    histories.iter().map(|history| extrapolate(history).0).sum()
+}
+
+pub fn process_part2(histories: &[Vec<i64>]) -> i64 {
+    histories.iter().map(|history| extrapolate(history).1).sum()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+
+    type Parsed<'a> = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed).to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed).to_string())
+    }
+}
+
+aoc_common::register_day!(Day09);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn test_extrapolate() {
+        assert_eq!(extrapolate(&[0, 3, 6, 9, 12, 15]), (18, -3));
+        assert_eq!(extrapolate(&[1, 3, 6, 10, 15, 21]), (28, 0));
+        assert_eq!(extrapolate(&[10, 13, 16, 21, 30, 45]), (68, 5));
+        assert_eq!(extrapolate(&[-2, -4, -6]), (-8, 0));
+        assert_eq!(extrapolate(&[7]), (7, 7));
+        assert_eq!(extrapolate(&[]), (0, 0));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            parse_input("1 2\n\n3 x").unwrap_err().to_string(),
+            "Line 3: Could not parse number: `x`: `3 x`"
+        );
+    }
+
+    #[test]
+    fn test_process_part1() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part1(&parsed_input);
+        assert_eq!(output, 114)
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part2(&parsed_input);
+        assert_eq!(output, 2)
+    }
+}
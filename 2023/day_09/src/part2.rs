@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_09::Day09, part2);
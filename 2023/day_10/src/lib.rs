@@ -0,0 +1,305 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec, vec::Vec};
+use aoc_common::{
+    grid::{Grid, GridError},
+    Answer, Solution, SolveError,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AOCError {
+    #[error("Could not parse the maze: {0}")]
+    GridError(#[from] GridError),
+    #[error("Unknown tile `{0}`")]
+    TileError(char),
+    #[error("Could not find the starting position")]
+    MissingStartError,
+    #[error("The maze has more than one starting position")]
+    MultipleStartsError,
+    #[error("The starting position connects to {0} pipes, expected 2")]
+    StartConnectionsError(usize),
+    #[error("The loop is broken at ({x}, {y})")]
+    BrokenLoopError { x: i32, y: i32 },
+}
+
+const NORTH: (i32, i32) = (0, -1);
+const SOUTH: (i32, i32) = (0, 1);
+const EAST: (i32, i32) = (1, 0);
+const WEST: (i32, i32) = (-1, 0);
+
+/// The directions a tile connects to. The starting position can connect to
+/// any of its neighbors.
+fn connections(tile: char) -> &'static [(i32, i32)] {
+    match tile {
+        '|' => &[NORTH, SOUTH],
+        '-' => &[EAST, WEST],
+        'L' => &[NORTH, EAST],
+        'J' => &[NORTH, WEST],
+        '7' => &[SOUTH, WEST],
+        'F' => &[SOUTH, EAST],
+        'S' => &[NORTH, SOUTH, EAST, WEST],
+        _ => &[],
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Maze {
+    grid: Grid<char>,
+    start: (i32, i32),
+}
+
+impl Maze {
+    pub fn start(&self) -> (i32, i32) {
+        self.start
+    }
+
+    /// The positions connected to `(x, y)` by a pipe, i.e. the neighbors the
+    /// tile points to that point back to it.
+    pub fn connected(&self, (x, y): (i32, i32)) -> Vec<(i32, i32)> {
+        let Some(&tile) = self.grid.get(x, y) else {
+            return Vec::new();
+        };
+        connections(tile)
+            .iter()
+            .map(|(dx, dy)| (x + dx, y + dy))
+            .filter(|&(nx, ny)| {
+                self.grid
+                    .get(nx, ny)
+                    .is_some_and(|&neighbor| connections(neighbor).contains(&(x - nx, y - ny)))
+            })
+            .collect()
+    }
+
+    /// The tiles of the loop through the starting position, in the order
+    /// they are visited and beginning with the starting position.
+    pub fn main_loop(&self) -> Result<Vec<(i32, i32)>, AOCError> {
+        let start_connections = self.connected(self.start);
+        if start_connections.len() != 2 {
+            return Err(AOCError::StartConnectionsError(start_connections.len()));
+        }
+
+        let mut tiles = vec![self.start];
+        let (mut previous, mut current) = (self.start, start_connections[0]);
+        while current != self.start {
+            tiles.push(current);
+            let next = self
+                .connected(current)
+                .into_iter()
+                .find(|&position| position != previous)
+                .ok_or(AOCError::BrokenLoopError {
+                    x: current.0,
+                    y: current.1,
+                })?;
+            (previous, current) = (current, next);
+        }
+        Ok(tiles)
+    }
+}
+
+impl core::str::FromStr for Maze {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid: Grid<char> = s.trim().parse()?;
+        let mut start = None;
+        for (position, &tile) in grid.iter() {
+            match tile {
+                'S' if start.is_some() => return Err(AOCError::MultipleStartsError),
+                'S' => start = Some(position),
+                '.' => {}
+                _ if connections(tile).is_empty() => return Err(AOCError::TileError(tile)),
+                _ => {}
+            }
+        }
+        let start = start.ok_or(AOCError::MissingStartError)?;
+        Ok(Maze { grid, start })
+    }
+}
+
+/// The number of tiles enclosed by a loop. The shoelace formula gives the
+/// area of the polygon through the centers of the loop tiles, and Pick's
+/// theorem `A = i + b / 2 - 1` the number of inner points `i` from it.
+pub fn enclosed_tiles(main_loop: &[(i32, i32)]) -> usize {
+    let twice_area = main_loop
+        .iter()
+        .zip(main_loop.iter().cycle().skip(1))
+        .map(|(&(x1, y1), &(x2, y2))| x1 as i64 * y2 as i64 - x2 as i64 * y1 as i64)
+        .sum::<i64>()
+        .unsigned_abs() as usize;
+    (twice_area + 2).saturating_sub(main_loop.len()) / 2
+}
+
+pub fn parse_input(input: &str) -> Result<Maze, AOCError> {
+    input.parse()
+}
+
+/// The number of steps to the tile of the loop that is farthest away from
+/// the starting position.
+pub fn process_part1(maze: &Maze) -> Result<usize, AOCError> {
+    Ok(maze.main_loop()?.len() / 2)
+}
+
+pub fn process_part2(maze: &Maze) -> Result<usize, AOCError> {
+    Ok(enclosed_tiles(&maze.main_loop()?))
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+
+    type Parsed<'a> = Maze;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed)?.to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed)?.to_string())
+    }
+}
+
+aoc_common::register_day!(Day10);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    const SQUARE: &str = ".....\n.S-7.\n.|.|.\n.L-J.\n.....";
+    const SQUARE_WITH_PIPES: &str = "-L|F7\n7S-7|\nL|7||\n-L-J|\nL|-JF";
+    const COMPLEX_WITH_PIPES: &str = "7-F7-\n.FJ|7\nSJLL7\n|F--J\nLJ.LJ";
+
+    const ENCLOSED: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+    const ENCLOSED_SQUEEZED: &str = "\
+..........
+.S------7.
+.|F----7|.
+.||....||.
+.||....||.
+.|L-7F-J|.
+.|..||..|.
+.L--JL--J.
+..........";
+
+    const ENCLOSED_LARGER: &str = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+
+    #[test]
+    fn test_parse_input() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let maze = parse_input(&input).unwrap();
+        assert_eq!(maze.start(), (0, 2));
+        assert_eq!(maze.connected((0, 2)), vec![(0, 3), (1, 2)]);
+        assert_eq!(maze.connected((2, 2)), vec![]);
+
+        assert!(matches!(
+            parse_input(".S.\n.X."),
+            Err(AOCError::TileError('X'))
+        ));
+        assert!(matches!(
+            parse_input("F7\nLJ"),
+            Err(AOCError::MissingStartError)
+        ));
+        assert!(matches!(
+            parse_input("S7\nLS"),
+            Err(AOCError::MultipleStartsError)
+        ));
+        assert!(matches!(parse_input("S7\nL"), Err(AOCError::GridError(_))));
+    }
+
+    #[test]
+    fn test_main_loop() {
+        let maze = parse_input(SQUARE).unwrap();
+        assert_eq!(
+            maze.main_loop().unwrap(),
+            vec![
+                (1, 1),
+                (1, 2),
+                (1, 3),
+                (2, 3),
+                (3, 3),
+                (3, 2),
+                (3, 1),
+                (2, 1)
+            ]
+        );
+        // unconnected pipes around the loop don't change it
+        let with_pipes = parse_input(SQUARE_WITH_PIPES).unwrap();
+        assert_eq!(with_pipes.main_loop().unwrap(), maze.main_loop().unwrap());
+
+        assert!(matches!(
+            parse_input("S-7\n|.|\nL-.").unwrap().main_loop(),
+            Err(AOCError::BrokenLoopError { .. })
+        ));
+        assert!(matches!(
+            parse_input("S-7\n|.|\nL-J").unwrap().main_loop(),
+            Ok(tiles) if tiles.len() == 8
+        ));
+        assert!(matches!(
+            parse_input(".S-\n.|.").unwrap().main_loop(),
+            Err(AOCError::BrokenLoopError { .. })
+        ));
+        assert!(matches!(
+            parse_input("-S-\n.|.").unwrap().main_loop(),
+            Err(AOCError::StartConnectionsError(3))
+        ));
+    }
+
+    #[test]
+    fn test_process_part1() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part1(&parsed_input).unwrap();
+        assert_eq!(output, 8);
+
+        for (maze, steps) in [(SQUARE, 4), (SQUARE_WITH_PIPES, 4), (COMPLEX_WITH_PIPES, 8)] {
+            assert_eq!(process_part1(&parse_input(maze).unwrap()).unwrap(), steps);
+        }
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input = fs::read_to_string("input_test_part2.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part2(&parsed_input).unwrap();
+        assert_eq!(output, 10);
+
+        for (maze, tiles) in [
+            (SQUARE, 1),
+            (SQUARE_WITH_PIPES, 1),
+            (ENCLOSED, 4),
+            (ENCLOSED_SQUEEZED, 4),
+            (ENCLOSED_LARGER, 8),
+        ] {
+            assert_eq!(process_part2(&parse_input(maze).unwrap()).unwrap(), tiles);
+        }
+    }
+}
@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_10::Day10, part2);
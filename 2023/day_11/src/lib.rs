@@ -0,0 +1,188 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec, vec::Vec};
+use aoc_common::{
+    grid::{Grid, GridError},
+    Answer, Solution, SolveError,
+};
+use core::str::FromStr;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AOCError {
+    #[error("Could not parse the image: {0}")]
+    GridError(#[from] GridError),
+    #[error("Unknown pixel `{0}`")]
+    PixelError(char),
+}
+
+/// The positions `(x, y)` of the galaxies in the image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    galaxies: Vec<(usize, usize)>,
+}
+
+impl Image {
+    pub fn galaxies(&self) -> &[(usize, usize)] {
+        &self.galaxies
+    }
+
+    /// The coordinates of the galaxies after every empty row and column has
+    /// been replaced by `factor` empty ones, `factor` needs to be at least 1.
+    pub fn expanded(&self, factor: usize) -> Vec<(usize, usize)> {
+        let offsets = |size: usize, coordinate: fn(&(usize, usize)) -> usize| {
+            let mut occupied = vec![false; size];
+            for galaxy in &self.galaxies {
+                occupied[coordinate(galaxy)] = true;
+            }
+            // the coordinate of each row or column after the expansion
+            occupied
+                .iter()
+                .scan(0, |empty, &occupied| {
+                    let offset = *empty * (factor - 1);
+                    *empty += usize::from(!occupied);
+                    Some(offset)
+                })
+                .collect::<Vec<_>>()
+        };
+        let (x_offsets, y_offsets) = (offsets(self.width, |g| g.0), offsets(self.height, |g| g.1));
+        self.galaxies
+            .iter()
+            .map(|&(x, y)| (x + x_offsets[x], y + y_offsets[y]))
+            .collect()
+    }
+}
+
+impl FromStr for Image {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid: Grid<char> = s.trim().parse()?;
+        let mut galaxies = Vec::new();
+        for ((x, y), &pixel) in grid.iter() {
+            match pixel {
+                '#' => galaxies.push((x as usize, y as usize)),
+                '.' => {}
+                _ => return Err(AOCError::PixelError(pixel)),
+            }
+        }
+        Ok(Image {
+            width: grid.width(),
+            height: grid.height(),
+            galaxies,
+        })
+    }
+}
+
+/// The sum of the distances between all pairs of values.
+fn sum_of_differences(mut values: Vec<usize>) -> usize {
+    values.sort_unstable();
+    let mut preceding = 0;
+    let mut sum = 0;
+    for (idx, value) in values.into_iter().enumerate() {
+        sum += value * idx - preceding;
+        preceding += value;
+    }
+    sum
+}
+
+/// The sum of the Manhattan distances between all pairs of galaxies, with
+/// empty rows and columns `factor` times as large. The distances along both
+/// axes are independent, so they are summed up separately.
+pub fn sum_of_distances(image: &Image, factor: usize) -> usize {
+    let (xs, ys) = image.expanded(factor).into_iter().unzip();
+    sum_of_differences(xs) + sum_of_differences(ys)
+}
+
+pub fn parse_input(input: &str) -> Result<Image, AOCError> {
+    input.parse()
+}
+
+pub fn process_part1(image: &Image) -> usize {
+    sum_of_distances(image, 2)
+}
+
+pub fn process_part2(image: &Image) -> usize {
+    sum_of_distances(image, 1_000_000)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+
+    type Parsed<'a> = Image;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed).to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed).to_string())
+    }
+}
+
+aoc_common::register_day!(Day11);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn test_parse_input() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let image = parse_input(&input).unwrap();
+        assert_eq!(image.galaxies().len(), 9);
+        assert_eq!(image.galaxies()[..3], [(3, 0), (7, 1), (0, 2)]);
+
+        assert!(matches!(
+            parse_input("#.\n.x"),
+            Err(AOCError::PixelError('x'))
+        ));
+        assert!(matches!(parse_input("#.\n."), Err(AOCError::GridError(_))));
+    }
+
+    #[test]
+    fn test_expanded() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let image = parse_input(&input).unwrap();
+        let expanded = image.expanded(2);
+        assert_eq!(expanded[..3], [(4, 0), (9, 1), (0, 2)]);
+        assert_eq!(expanded[8], (5, 11));
+        assert_eq!(image.expanded(1), image.galaxies());
+    }
+
+    #[test]
+    fn test_sum_of_distances() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let image = parse_input(&input).unwrap();
+        assert_eq!(sum_of_distances(&image, 10), 1030);
+        assert_eq!(sum_of_distances(&image, 100), 8410);
+    }
+
+    #[test]
+    fn test_process_part1() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part1(&parsed_input);
+        assert_eq!(output, 374)
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part2(&parsed_input);
+        assert_eq!(output, 82000210)
+    }
+}
@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_11::Day11, part1);
@@ -0,0 +1,273 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use aoc_common::{collections::HashMap, Answer, Solution, SolveError};
+use core::{num::ParseIntError, str::FromStr};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AOCError {
+    #[error("Did not find the group sizes")]
+    MissingGroupsError,
+    #[error("Unknown spring `{0}`")]
+    SpringError(char),
+    #[error("Could not parse number: `{0}`")]
+    ParseNumberError(String),
+    #[error("Line {line}: {error}: `{content}`")]
+    InvalidLineError {
+        line: usize,
+        content: String,
+        error: Box<AOCError>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl TryFrom<char> for Spring {
+    type Error = AOCError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Spring::Operational),
+            '#' => Ok(Spring::Damaged),
+            '?' => Ok(Spring::Unknown),
+            _ => Err(AOCError::SpringError(value)),
+        }
+    }
+}
+
+/// A row of springs and the sizes of the groups of damaged springs in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Row {
+    springs: Vec<Spring>,
+    groups: Vec<usize>,
+}
+
+impl Row {
+    pub fn springs(&self) -> &[Spring] {
+        &self.springs
+    }
+
+    pub fn groups(&self) -> &[usize] {
+        &self.groups
+    }
+
+    /// The row repeated `times` times, with the springs separated by an
+    /// unknown spring.
+    pub fn unfold(&self, times: usize) -> Row {
+        let mut springs = Vec::new();
+        for idx in 0..times {
+            if idx > 0 {
+                springs.push(Spring::Unknown);
+            }
+            springs.extend_from_slice(&self.springs);
+        }
+        Row {
+            springs,
+            groups: self.groups.repeat(times),
+        }
+    }
+
+    /// The number of ways the unknown springs can be operational or damaged
+    /// so that the row matches its groups.
+    pub fn arrangements(&self) -> usize {
+        self.count(0, 0, 0, &mut HashMap::new())
+    }
+
+    /// The arrangements of the springs from `position` on, with the groups
+    /// before `group` complete and `run` damaged springs of `group` directly
+    /// before `position`.
+    fn count(
+        &self,
+        position: usize,
+        group: usize,
+        run: usize,
+        memo: &mut HashMap<(usize, usize, usize), usize>,
+    ) -> usize {
+        let Some(&spring) = self.springs.get(position) else {
+            let complete = (group == self.groups.len() && run == 0)
+                || (group + 1 == self.groups.len() && run == self.groups[group]);
+            return usize::from(complete);
+        };
+        if let Some(&count) = memo.get(&(position, group, run)) {
+            return count;
+        }
+
+        let mut count = 0;
+        if spring != Spring::Operational && self.groups.get(group).is_some_and(|&size| run < size) {
+            count += self.count(position + 1, group, run + 1, memo);
+        }
+        if spring != Spring::Damaged {
+            if run == 0 {
+                count += self.count(position + 1, group, 0, memo);
+            } else if run == self.groups[group] {
+                count += self.count(position + 1, group + 1, 0, memo);
+            }
+        }
+        memo.insert((position, group, run), count);
+        count
+    }
+}
+
+impl FromStr for Row {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (springs, groups) = s
+            .trim()
+            .split_once(' ')
+            .ok_or(AOCError::MissingGroupsError)?;
+        let springs = springs
+            .chars()
+            .map(Spring::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let groups = groups
+            .split(',')
+            .map(|num| {
+                num.trim()
+                    .parse()
+                    .map_err(|e: ParseIntError| AOCError::ParseNumberError(e.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Row { springs, groups })
+    }
+}
+
+/// Parse one row per line, empty lines are skipped.
+pub fn parse_input(input: &str) -> Result<Vec<Row>, AOCError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            line.parse::<Row>()
+                .map_err(|error| AOCError::InvalidLineError {
+                    line: idx + 1,
+                    content: line.to_string(),
+                    error: Box::new(error),
+                })
+        })
+        .collect()
+}
+
+pub fn process_part1(rows: &[Row]) -> usize {
+    rows.iter().map(Row::arrangements).sum()
+}
+
+pub fn process_part2(rows: &[Row]) -> usize {
+    rows.iter().map(|row| row.unfold(5).arrangements()).sum()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
+
+    type Parsed<'a> = Vec<Row>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed).to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed).to_string())
+    }
+}
+
+aoc_common::register_day!(Day12);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn test_parse_input() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let rows = parse_input(&input).unwrap();
+        assert_eq!(rows.len(), 6);
+        assert_eq!(
+            rows[0].springs(),
+            &[
+                Spring::Unknown,
+                Spring::Unknown,
+                Spring::Unknown,
+                Spring::Operational,
+                Spring::Damaged,
+                Spring::Damaged,
+                Spring::Damaged
+            ]
+        );
+        assert_eq!(rows[0].groups(), &[1, 1, 3]);
+
+        assert!(matches!(
+            parse_input("#.# 1,1\n#x# 1,1"),
+            Err(AOCError::InvalidLineError { line: 2, error, .. })
+                if matches!(*error, AOCError::SpringError('x'))
+        ));
+        assert!(matches!(
+            parse_input("#.#"),
+            Err(AOCError::InvalidLineError { error, .. })
+                if matches!(*error, AOCError::MissingGroupsError)
+        ));
+        assert!(matches!(
+            parse_input("#.# 1,a"),
+            Err(AOCError::InvalidLineError { error, .. })
+                if matches!(*error, AOCError::ParseNumberError(_))
+        ));
+    }
+
+    #[test]
+    fn test_unfold() {
+        let row: Row = ".# 1".parse().unwrap();
+        assert_eq!(row.unfold(5), ".#?.#?.#?.#?.# 1,1,1,1,1".parse().unwrap());
+        assert_eq!(row.unfold(1), row);
+    }
+
+    #[test]
+    fn test_arrangements() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let rows = parse_input(&input).unwrap();
+        let arrangements: Vec<usize> = rows.iter().map(Row::arrangements).collect();
+        assert_eq!(arrangements, vec![1, 4, 1, 1, 4, 10]);
+        let unfolded: Vec<usize> = rows.iter().map(|r| r.unfold(5).arrangements()).collect();
+        assert_eq!(unfolded, vec![1, 16384, 1, 16, 2500, 506250]);
+
+        let row: Row = "### 2".parse().unwrap();
+        assert_eq!(row.arrangements(), 0);
+        let row: Row = "??? 4".parse().unwrap();
+        assert_eq!(row.arrangements(), 0);
+    }
+
+    #[test]
+    fn test_process_part1() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part1(&parsed_input);
+        assert_eq!(output, 21)
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part2(&parsed_input);
+        assert_eq!(output, 525152)
+    }
+}
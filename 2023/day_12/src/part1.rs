@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_12::Day12, part1);
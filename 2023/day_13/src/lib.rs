@@ -0,0 +1,216 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec::Vec};
+use aoc_common::{
+    grid::{Grid, GridError},
+    Answer, Solution, SolveError,
+};
+use core::str::FromStr;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AOCError {
+    #[error("Could not parse the pattern: {0}")]
+    GridError(#[from] GridError),
+    #[error("Unknown tile `{0}`")]
+    TileError(char),
+    #[error("Pattern {0} has no line of reflection")]
+    NoReflectionError(usize),
+}
+
+/// A line of reflection, given by the number of columns to its left or the
+/// number of rows above it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reflection {
+    Vertical(usize),
+    Horizontal(usize),
+}
+
+impl Reflection {
+    pub fn score(&self) -> usize {
+        match self {
+            Reflection::Vertical(columns) => *columns,
+            Reflection::Horizontal(rows) => 100 * rows,
+        }
+    }
+}
+
+/// The rocks (`true`) and ash (`false`) of a pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    rows: Vec<Vec<bool>>,
+    columns: Vec<Vec<bool>>,
+}
+
+impl Pattern {
+    /// The line of reflection for which exactly `smudges` tiles differ from
+    /// their mirror image.
+    pub fn reflection(&self, smudges: usize) -> Option<Reflection> {
+        reflection(&self.columns, smudges)
+            .map(Reflection::Vertical)
+            .or_else(|| reflection(&self.rows, smudges).map(Reflection::Horizontal))
+    }
+}
+
+/// The number of lines before a line of reflection of `lines`, for which
+/// exactly `smudges` tiles differ.
+fn reflection(lines: &[Vec<bool>], smudges: usize) -> Option<usize> {
+    (1..lines.len()).find(|&before| {
+        let mismatches: usize = lines[..before]
+            .iter()
+            .rev()
+            .zip(&lines[before..])
+            .map(|(a, b)| a.iter().zip(b).filter(|(a, b)| a != b).count())
+            .sum();
+        mismatches == smudges
+    })
+}
+
+impl FromStr for Pattern {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid: Grid<char> = s.trim().parse()?;
+        let rows = grid
+            .rows()
+            .map(|row| {
+                row.iter()
+                    .map(|&tile| match tile {
+                        '#' => Ok(true),
+                        '.' => Ok(false),
+                        _ => Err(AOCError::TileError(tile)),
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let columns = (0..grid.width())
+            .map(|x| rows.iter().map(|row| row[x]).collect())
+            .collect();
+        Ok(Pattern { rows, columns })
+    }
+}
+
+/// The score of `pattern` for its line of reflection with exactly `smudges`
+/// differing tiles, `None` if there is no such line.
+pub fn score(pattern: &Pattern, smudges: usize) -> Option<usize> {
+    pattern.reflection(smudges).map(|r| r.score())
+}
+
+/// Parse the patterns, which are separated by empty lines.
+pub fn parse_input(input: &str) -> Result<Vec<Pattern>, AOCError> {
+    input.trim().split("\n\n").map(str::parse).collect()
+}
+
+fn summarize(patterns: &[Pattern], smudges: usize) -> Result<usize, AOCError> {
+    patterns
+        .iter()
+        .enumerate()
+        .map(|(idx, pattern)| score(pattern, smudges).ok_or(AOCError::NoReflectionError(idx + 1)))
+        .sum()
+}
+
+pub fn process_part1(patterns: &[Pattern]) -> Result<usize, AOCError> {
+    summarize(patterns, 0)
+}
+
+/// Every pattern has exactly one smudge, which moves its line of reflection.
+pub fn process_part2(patterns: &[Pattern]) -> Result<usize, AOCError> {
+    summarize(patterns, 1)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+
+    type Parsed<'a> = Vec<Pattern>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed)?.to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed)?.to_string())
+    }
+}
+
+aoc_common::register_day!(Day13);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn test_parse_input() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let patterns = parse_input(&input).unwrap();
+        assert_eq!(patterns.len(), 2);
+        assert_eq!(patterns[0].rows.len(), 7);
+        assert_eq!(patterns[0].columns.len(), 9);
+        assert_eq!(
+            patterns[1].columns[0],
+            vec![true, true, false, true, true, false, true]
+        );
+
+        assert!(matches!(
+            parse_input("#.\n.x"),
+            Err(AOCError::TileError('x'))
+        ));
+        assert!(matches!(parse_input("#.\n."), Err(AOCError::GridError(_))));
+    }
+
+    #[test]
+    fn test_reflection() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let patterns = parse_input(&input).unwrap();
+        assert_eq!(patterns[0].reflection(0), Some(Reflection::Vertical(5)));
+        assert_eq!(patterns[1].reflection(0), Some(Reflection::Horizontal(4)));
+        assert_eq!(patterns[0].reflection(1), Some(Reflection::Horizontal(3)));
+        assert_eq!(patterns[1].reflection(1), Some(Reflection::Horizontal(1)));
+
+        let pattern: Pattern = "#.\n..".parse().unwrap();
+        assert_eq!(pattern.reflection(0), None);
+        assert_eq!(pattern.reflection(1), Some(Reflection::Vertical(1)));
+    }
+
+    #[test]
+    fn test_score() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let patterns = parse_input(&input).unwrap();
+        assert_eq!(score(&patterns[0], 0), Some(5));
+        assert_eq!(score(&patterns[1], 0), Some(400));
+        assert_eq!(score(&patterns[0], 1), Some(300));
+        assert_eq!(score(&patterns[1], 1), Some(100));
+        assert_eq!(score(&"#.\n.#".parse().unwrap(), 0), None);
+    }
+
+    #[test]
+    fn test_process_part1() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part1(&parsed_input).unwrap();
+        assert_eq!(output, 405);
+
+        let parsed_input = parse_input("#.\n..\n\n#.\n.#").unwrap();
+        assert!(matches!(
+            process_part1(&parsed_input),
+            Err(AOCError::NoReflectionError(1))
+        ));
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part2(&parsed_input).unwrap();
+        assert_eq!(output, 400)
+    }
+}
@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_13::Day13, part1);
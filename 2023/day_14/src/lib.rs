@@ -0,0 +1,325 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use aoc_common::{
+    collections::HashMap,
+    grid::{Grid, GridError},
+    Answer, Solution, SolveError,
+};
+use core::{fmt, str::FromStr};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AOCError {
+    #[error("Could not parse the platform: {0}")]
+    GridError(#[from] GridError),
+    #[error("Unknown tile `{0}`")]
+    TileError(char),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tile {
+    RoundRock,
+    CubeRock,
+    Empty,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = AOCError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'O' => Ok(Tile::RoundRock),
+            '#' => Ok(Tile::CubeRock),
+            '.' => Ok(Tile::Empty),
+            _ => Err(AOCError::TileError(value)),
+        }
+    }
+}
+
+impl From<Tile> for char {
+    fn from(tile: Tile) -> Self {
+        match tile {
+            Tile::RoundRock => 'O',
+            Tile::CubeRock => '#',
+            Tile::Empty => '.',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    North,
+    West,
+    South,
+    East,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Platform {
+    grid: Grid<Tile>,
+}
+
+impl Platform {
+    /// Roll all round rocks as far as possible towards `direction`.
+    pub fn tilt(&mut self, direction: Direction) {
+        let (width, height) = (self.grid.width() as i32, self.grid.height() as i32);
+        // the lines the rocks roll along, and the position of the `step`th
+        // tile of a line starting at the edge the rocks roll to
+        let (lines, length) = match direction {
+            Direction::North | Direction::South => (width, height),
+            Direction::West | Direction::East => (height, width),
+        };
+        let position = |line: i32, step: i32| match direction {
+            Direction::North => (line, step),
+            Direction::South => (line, height - 1 - step),
+            Direction::West => (step, line),
+            Direction::East => (width - 1 - step, line),
+        };
+
+        for line in 0..lines {
+            let mut free = 0;
+            for step in 0..length {
+                let (x, y) = position(line, step);
+                match self.grid.get(x, y) {
+                    Some(Tile::CubeRock) => free = step + 1,
+                    Some(Tile::RoundRock) => {
+                        let (free_x, free_y) = position(line, free);
+                        *self.grid.get_mut(x, y).unwrap() = Tile::Empty;
+                        *self.grid.get_mut(free_x, free_y).unwrap() = Tile::RoundRock;
+                        free += 1;
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    /// Tilt the platform north, west, south and then east.
+    pub fn spin_cycle(&mut self) {
+        for direction in [
+            Direction::North,
+            Direction::West,
+            Direction::South,
+            Direction::East,
+        ] {
+            self.tilt(direction);
+        }
+    }
+
+    /// The total load on the north support beams. Each round rock adds the
+    /// number of rows from it to the south edge, including its own row.
+    pub fn load(&self) -> usize {
+        self.grid
+            .iter()
+            .filter(|(_, tile)| **tile == Tile::RoundRock)
+            .map(|((_, y), _)| self.grid.height() - y as usize)
+            .sum()
+    }
+}
+
+impl FromStr for Platform {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows = s
+            .trim()
+            .lines()
+            .map(|line| line.chars().map(Tile::try_from).collect())
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Platform {
+            grid: Grid::from_rows(rows)?,
+        })
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.grid.rows() {
+            let row: String = row.iter().map(|&tile| char::from(tile)).collect();
+            writeln!(f, "{row}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The platform after `cycles` spin cycles. Once a state repeats the
+/// platform is in a loop, so the remaining full loops are skipped.
+pub fn spin(platform: &Platform, cycles: usize) -> Platform {
+    let mut platform = platform.clone();
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    for cycle in 0..cycles {
+        if let Some(&start) = seen.get(&platform) {
+            let remaining = (cycles - cycle) % (cycle - start);
+            return states.swap_remove(start + remaining);
+        }
+        seen.insert(platform.clone(), cycle);
+        states.push(platform.clone());
+        platform.spin_cycle();
+    }
+    platform
+}
+
+pub fn parse_input(input: &str) -> Result<Platform, AOCError> {
+    input.parse()
+}
+
+pub fn process_part1(platform: &Platform) -> usize {
+    let mut platform = platform.clone();
+    platform.tilt(Direction::North);
+    platform.load()
+}
+
+pub fn process_part2(platform: &Platform) -> usize {
+    spin(platform, 1_000_000_000).load()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+
+    type Parsed<'a> = Platform;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed).to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed).to_string())
+    }
+}
+
+aoc_common::register_day!(Day14);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    const TILTED_NORTH: &str = "\
+OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#....
+";
+
+    const AFTER_CYCLES: [&str; 3] = [
+        "\
+.....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....
+",
+        "\
+.....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#..OO###..
+#.OOO#...O
+",
+        "\
+.....#....
+....#...O#
+.....##...
+..O#......
+.....OOO#.
+.O#...O#.#
+....O#...O
+.......OOO
+#...O###.O
+#.OOO#...O
+",
+    ];
+
+    #[test]
+    fn test_parse_input() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let platform = parse_input(&input).unwrap();
+        assert_eq!(platform.to_string(), input);
+        assert_eq!(platform.load(), 104);
+
+        assert!(matches!(
+            parse_input("O.\n.x"),
+            Err(AOCError::TileError('x'))
+        ));
+        assert!(matches!(parse_input("O.\n."), Err(AOCError::GridError(_))));
+    }
+
+    #[test]
+    fn test_tilt() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let mut platform = parse_input(&input).unwrap();
+        platform.tilt(Direction::North);
+        assert_eq!(platform.to_string(), TILTED_NORTH);
+        assert_eq!(platform.load(), 136);
+
+        let mut platform: Platform = "O.O#.O".parse().unwrap();
+        platform.tilt(Direction::East);
+        assert_eq!(platform.to_string(), ".OO#.O\n");
+        platform.tilt(Direction::West);
+        assert_eq!(platform.to_string(), "OO.#O.\n");
+    }
+
+    #[test]
+    fn test_spin_cycle() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let mut platform = parse_input(&input).unwrap();
+        for expected in AFTER_CYCLES {
+            platform.spin_cycle();
+            assert_eq!(platform.to_string(), expected);
+        }
+
+        let platform = parse_input(&input).unwrap();
+        assert_eq!(spin(&platform, 0), platform);
+        for (cycles, expected) in AFTER_CYCLES.iter().enumerate() {
+            assert_eq!(spin(&platform, cycles + 1).to_string(), *expected);
+        }
+        // the loop starts after 3 cycles and takes 7 cycles
+        assert_eq!(spin(&platform, 3 + 7 * 1000), spin(&platform, 3));
+    }
+
+    #[test]
+    fn test_process_part1() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part1(&parsed_input);
+        assert_eq!(output, 136)
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part2(&parsed_input);
+        assert_eq!(output, 64)
+    }
+}
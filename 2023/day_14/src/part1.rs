@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_14::Day14, part1);
@@ -0,0 +1,273 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use aoc_common::{Answer, Solution, SolveError};
+use core::{fmt, str::FromStr};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AOCError {
+    #[error("Could not parse step: `{0}`")]
+    StepParseError(String),
+}
+
+/// The Holiday ASCII String Helper algorithm.
+pub fn hash(s: &str) -> u8 {
+    s.bytes()
+        .fold(0, |value, byte| value.wrapping_add(byte).wrapping_mul(17))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Remove,
+    /// Insert or replace a lens with the focal length
+    Insert(u8),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    label: String,
+    operation: Operation,
+}
+
+impl Step {
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    pub fn operation(&self) -> Operation {
+        self.operation
+    }
+}
+
+impl FromStr for Step {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || AOCError::StepParseError(s.to_string());
+        let (label, operation) = if let Some(label) = s.strip_suffix('-') {
+            (label, Operation::Remove)
+        } else {
+            let (label, focal_length) = s.split_once('=').ok_or_else(error)?;
+            // focal lengths range from 1 to 9, so a step is exactly its
+            // `Display` output, which part 1 hashes
+            let focal_length = match focal_length.as_bytes() {
+                [digit @ b'1'..=b'9'] => digit - b'0',
+                _ => return Err(error()),
+            };
+            (label, Operation::Insert(focal_length))
+        };
+        if label.is_empty() || !label.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(error());
+        }
+        Ok(Step {
+            label: label.to_string(),
+            operation,
+        })
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.operation {
+            Operation::Remove => write!(f, "{}-", self.label),
+            Operation::Insert(focal_length) => write!(f, "{}={focal_length}", self.label),
+        }
+    }
+}
+
+/// The 256 boxes, each with its lenses in order as label and focal length.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Boxes {
+    boxes: Vec<Vec<(String, u8)>>,
+}
+
+impl Default for Boxes {
+    fn default() -> Self {
+        Boxes {
+            boxes: vec![Vec::new(); 256],
+        }
+    }
+}
+
+impl Boxes {
+    pub fn new() -> Boxes {
+        Boxes::default()
+    }
+
+    /// The lenses of box `idx`.
+    pub fn lenses(&self, idx: u8) -> &[(String, u8)] {
+        &self.boxes[idx as usize]
+    }
+
+    /// Remove the lens with the label of `step` from its box, or insert it.
+    /// An inserted lens replaces the lens with the same label in place, and
+    /// is put behind all other lenses otherwise.
+    pub fn apply(&mut self, step: &Step) {
+        let lenses = &mut self.boxes[hash(&step.label) as usize];
+        let position = lenses.iter().position(|(label, _)| *label == step.label);
+        match (step.operation, position) {
+            (Operation::Remove, Some(position)) => {
+                lenses.remove(position);
+            }
+            (Operation::Remove, None) => {}
+            (Operation::Insert(focal_length), Some(position)) => {
+                lenses[position].1 = focal_length;
+            }
+            (Operation::Insert(focal_length), None) => {
+                lenses.push((step.label.clone(), focal_length));
+            }
+        }
+    }
+
+    pub fn focusing_power(&self) -> usize {
+        self.boxes
+            .iter()
+            .enumerate()
+            .flat_map(|(box_idx, lenses)| {
+                lenses
+                    .iter()
+                    .enumerate()
+                    .map(move |(slot, (_, focal_length))| {
+                        (box_idx + 1) * (slot + 1) * *focal_length as usize
+                    })
+            })
+            .sum()
+    }
+}
+
+/// Parse the comma separated steps, newlines are ignored.
+pub fn parse_input(input: &str) -> Result<Vec<Step>, AOCError> {
+    input
+        .replace('\n', "")
+        .split(',')
+        .map(str::trim)
+        .filter(|step| !step.is_empty())
+        .map(str::parse)
+        .collect()
+}
+
+pub fn process_part1(steps: &[Step]) -> usize {
+    steps
+        .iter()
+        .map(|step| hash(&step.to_string()) as usize)
+        .sum()
+}
+
+pub fn process_part2(steps: &[Step]) -> usize {
+    let mut boxes = Boxes::new();
+    for step in steps {
+        boxes.apply(step);
+    }
+    boxes.focusing_power()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 15;
+
+    type Parsed<'a> = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed).to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed).to_string())
+    }
+}
+
+aoc_common::register_day!(Day15);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash("HASH"), 52);
+        assert_eq!(hash("rn=1"), 30);
+        assert_eq!(hash("rn"), 0);
+        assert_eq!(hash("qp"), 1);
+        assert_eq!(hash(""), 0);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let steps = parse_input(&input).unwrap();
+        assert_eq!(steps.len(), 11);
+        assert_eq!(steps[0].label(), "rn");
+        assert_eq!(steps[0].operation(), Operation::Insert(1));
+        assert_eq!(steps[1].operation(), Operation::Remove);
+        let steps: Vec<String> = steps.iter().map(Step::to_string).collect();
+        assert_eq!(steps.join(","), input.trim());
+
+        for step in ["rn", "rn=", "rn=x", "rn=0", "rn=01", "=1", "-", "r1=2"] {
+            assert!(
+                matches!(parse_input(step), Err(AOCError::StepParseError(s)) if s == step),
+                "{step}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_boxes() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let mut boxes = Boxes::new();
+        let steps = parse_input(&input).unwrap();
+        for step in &steps[..5] {
+            boxes.apply(step);
+        }
+        assert_eq!(
+            boxes.lenses(0),
+            &[("rn".to_string(), 1), ("cm".to_string(), 2)]
+        );
+        assert_eq!(boxes.lenses(1), &[]);
+        for step in &steps[5..] {
+            boxes.apply(step);
+        }
+        assert_eq!(
+            boxes.lenses(0),
+            &[("rn".to_string(), 1), ("cm".to_string(), 2)]
+        );
+        assert_eq!(
+            boxes.lenses(3),
+            &[
+                ("ot".to_string(), 7),
+                ("ab".to_string(), 5),
+                ("pc".to_string(), 6)
+            ]
+        );
+        assert_eq!(boxes.focusing_power(), 145);
+    }
+
+    #[test]
+    fn test_process_part1() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part1(&parsed_input);
+        assert_eq!(output, 1320)
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part2(&parsed_input);
+        assert_eq!(output, 145)
+    }
+}
@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_15::Day15, part2);
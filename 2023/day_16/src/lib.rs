@@ -0,0 +1,252 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec, vec::Vec};
+use aoc_common::{
+    grid::{Grid, GridError},
+    Answer, Solution, SolveError,
+};
+use core::str::FromStr;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AOCError {
+    #[error("Could not parse the contraption: {0}")]
+    GridError(#[from] GridError),
+    #[error("Unknown tile `{0}`")]
+    TileError(char),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    /// `/`
+    MirrorUp,
+    /// `\`
+    MirrorDown,
+    /// `|`
+    SplitterVertical,
+    /// `-`
+    SplitterHorizontal,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = AOCError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Tile::Empty),
+            '/' => Ok(Tile::MirrorUp),
+            '\\' => Ok(Tile::MirrorDown),
+            '|' => Ok(Tile::SplitterVertical),
+            '-' => Ok(Tile::SplitterHorizontal),
+            _ => Err(AOCError::TileError(value)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn offset(self) -> (i32, i32) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        }
+    }
+
+    /// The bit of the direction in the visited mask of a tile.
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+impl Tile {
+    /// The directions a beam travelling in `direction` leaves this tile in.
+    fn deflect(self, direction: Direction) -> (Direction, Option<Direction>) {
+        use Direction::*;
+        match (self, direction) {
+            (Tile::MirrorUp, Right) | (Tile::MirrorDown, Left) => (Up, None),
+            (Tile::MirrorUp, Left) | (Tile::MirrorDown, Right) => (Down, None),
+            (Tile::MirrorUp, Down) | (Tile::MirrorDown, Up) => (Left, None),
+            (Tile::MirrorUp, Up) | (Tile::MirrorDown, Down) => (Right, None),
+            (Tile::SplitterVertical, Left | Right) => (Up, Some(Down)),
+            (Tile::SplitterHorizontal, Up | Down) => (Left, Some(Right)),
+            _ => (direction, None),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contraption {
+    grid: Grid<Tile>,
+}
+
+impl Contraption {
+    /// The number of tiles a beam entering at `start` in `direction` passes
+    /// through. Beams are followed until they leave the grid or reach a tile
+    /// that a beam in the same direction passed before.
+    pub fn energized(&self, start: (i32, i32), direction: Direction) -> usize {
+        let (width, height) = (self.grid.width(), self.grid.height());
+        let mut visited = Grid::from_rows(vec![vec![0_u8; width]; height])
+            .expect("the grid has the size of the contraption");
+        let mut beams = vec![(start, direction)];
+        while let Some(((x, y), direction)) = beams.pop() {
+            let (Some(tile), Some(mask)) = (self.grid.get(x, y), visited.get_mut(x, y)) else {
+                continue;
+            };
+            if *mask & direction.bit() != 0 {
+                continue;
+            }
+            *mask |= direction.bit();
+
+            let (first, second) = tile.deflect(direction);
+            for direction in core::iter::once(first).chain(second) {
+                let (dx, dy) = direction.offset();
+                beams.push(((x + dx, y + dy), direction));
+            }
+        }
+        visited.iter().filter(|(_, mask)| **mask != 0).count()
+    }
+
+    /// All positions and directions a beam can enter the grid from.
+    pub fn entries(&self) -> Vec<((i32, i32), Direction)> {
+        let (width, height) = (self.grid.width() as i32, self.grid.height() as i32);
+        let mut entries = Vec::new();
+        for x in 0..width {
+            entries.push(((x, 0), Direction::Down));
+            entries.push(((x, height - 1), Direction::Up));
+        }
+        for y in 0..height {
+            entries.push(((0, y), Direction::Right));
+            entries.push(((width - 1, y), Direction::Left));
+        }
+        entries
+    }
+}
+
+impl FromStr for Contraption {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows = s
+            .trim()
+            .lines()
+            .map(|line| line.chars().map(Tile::try_from).collect())
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Contraption {
+            grid: Grid::from_rows(rows)?,
+        })
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Contraption, AOCError> {
+    input.parse()
+}
+
+pub fn process_part1(contraption: &Contraption) -> usize {
+    contraption.energized((0, 0), Direction::Right)
+}
+
+/// The most tiles any beam entering from the edge energizes.
+pub fn process_part2(contraption: &Contraption) -> usize {
+    // the beams of the entries are independent of each other
+    #[cfg(feature = "parallel")]
+    let energized = {
+        use rayon::prelude::*;
+        contraption
+            .entries()
+            .into_par_iter()
+            .map(|(start, direction)| contraption.energized(start, direction))
+            .max()
+    };
+    #[cfg(not(feature = "parallel"))]
+    let energized = contraption
+        .entries()
+        .into_iter()
+        .map(|(start, direction)| contraption.energized(start, direction))
+        .max();
+    energized.unwrap_or(0)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 16;
+
+    type Parsed<'a> = Contraption;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed).to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed).to_string())
+    }
+}
+
+aoc_common::register_day!(Day16);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn test_parse_input() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let contraption = parse_input(&input).unwrap();
+        assert_eq!(contraption.grid.get(1, 0), Some(&Tile::SplitterVertical));
+        assert_eq!(contraption.grid.get(5, 0), Some(&Tile::MirrorDown));
+        assert_eq!(contraption.grid.get(4, 6), Some(&Tile::MirrorUp));
+        assert_eq!(contraption.entries().len(), 40);
+
+        assert!(matches!(
+            parse_input(".|\n.x"),
+            Err(AOCError::TileError('x'))
+        ));
+        assert!(matches!(parse_input(".|\n."), Err(AOCError::GridError(_))));
+    }
+
+    #[test]
+    fn test_energized() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let contraption = parse_input(&input).unwrap();
+        assert_eq!(contraption.energized((3, 0), Direction::Down), 51);
+        assert_eq!(contraption.energized((0, 0), Direction::Down), 10);
+
+        // the beam loops between the mirrors
+        let contraption = parse_input("/.\\\n...\n\\./").unwrap();
+        assert_eq!(contraption.energized((1, 0), Direction::Right), 8);
+    }
+
+    #[test]
+    fn test_process_part1() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part1(&parsed_input);
+        assert_eq!(output, 46)
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part2(&parsed_input);
+        assert_eq!(output, 51)
+    }
+}
@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_16::Day16, part1);
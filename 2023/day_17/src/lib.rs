@@ -0,0 +1,246 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{collections::BinaryHeap, string::ToString, vec, vec::Vec};
+use aoc_common::{
+    grid::{Grid, GridError},
+    Answer, Solution, SolveError,
+};
+use core::{cmp::Reverse, str::FromStr};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AOCError {
+    #[error("Could not parse the map: {0}")]
+    GridError(#[from] GridError),
+    #[error("Heat loss needs to be a digit, found `{0}`")]
+    DigitError(char),
+    #[error("The crucible can't reach the factory")]
+    NoPathError,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn offset(self) -> (i32, i32) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        }
+    }
+
+    fn turns(self) -> [Direction; 2] {
+        match self {
+            Direction::Up | Direction::Down => [Direction::Left, Direction::Right],
+            Direction::Left | Direction::Right => [Direction::Up, Direction::Down],
+        }
+    }
+}
+
+/// How many blocks a crucible moves in a straight line at least before it
+/// can turn or stop, and at most before it has to turn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Crucible {
+    pub min_straight: usize,
+    pub max_straight: usize,
+}
+
+impl Crucible {
+    pub const NORMAL: Crucible = Crucible {
+        min_straight: 1,
+        max_straight: 3,
+    };
+    pub const ULTRA: Crucible = Crucible {
+        min_straight: 4,
+        max_straight: 10,
+    };
+}
+
+/// The heat loss of every city block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct City {
+    grid: Grid<u8>,
+}
+
+/// A position, the direction the crucible moved in to get there and how many
+/// blocks it moved in that direction.
+type State = ((i32, i32), Direction, usize);
+
+impl City {
+    /// The least heat loss from the top left to the bottom right block,
+    /// found with Dijkstra's algorithm over the states of the crucible.
+    pub fn minimal_heat_loss(&self, crucible: Crucible) -> Option<usize> {
+        let (width, height) = (self.grid.width(), self.grid.height());
+        let target = (width as i32 - 1, height as i32 - 1);
+        let index = |((x, y), direction, run): State| {
+            ((y as usize * width + x as usize) * 4 + direction as usize)
+                * (crucible.max_straight + 1)
+                + run
+        };
+        let mut best = vec![usize::MAX; width * height * 4 * (crucible.max_straight + 1)];
+
+        let mut queue = BinaryHeap::new();
+        for direction in [Direction::Right, Direction::Down] {
+            let state = ((0, 0), direction, 0);
+            best[index(state)] = 0;
+            queue.push(Reverse((0, state)));
+        }
+        while let Some(Reverse((heat_loss, state))) = queue.pop() {
+            let (position, direction, run) = state;
+            if heat_loss > best[index(state)] {
+                continue;
+            }
+            if position == target && run >= crucible.min_straight {
+                return Some(heat_loss);
+            }
+
+            let mut moves = Vec::with_capacity(3);
+            if run < crucible.max_straight {
+                moves.push((direction, run + 1));
+            }
+            if run >= crucible.min_straight {
+                moves.extend(direction.turns().map(|turn| (turn, 1)));
+            }
+            for (direction, run) in moves {
+                let (dx, dy) = direction.offset();
+                let next = (position.0 + dx, position.1 + dy);
+                let Some(&loss) = self.grid.get(next.0, next.1) else {
+                    continue;
+                };
+                let state = (next, direction, run);
+                let heat_loss = heat_loss + loss as usize;
+                if heat_loss < best[index(state)] {
+                    best[index(state)] = heat_loss;
+                    queue.push(Reverse((heat_loss, state)));
+                }
+            }
+        }
+        None
+    }
+}
+
+impl FromStr for City {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rows = s
+            .trim()
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|c| {
+                        c.to_digit(10)
+                            .map(|digit| digit as u8)
+                            .ok_or(AOCError::DigitError(c))
+                    })
+                    .collect()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(City {
+            grid: Grid::from_rows(rows)?,
+        })
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<City, AOCError> {
+    input.parse()
+}
+
+pub fn process_part1(city: &City) -> Result<usize, AOCError> {
+    city.minimal_heat_loss(Crucible::NORMAL)
+        .ok_or(AOCError::NoPathError)
+}
+
+pub fn process_part2(city: &City) -> Result<usize, AOCError> {
+    city.minimal_heat_loss(Crucible::ULTRA)
+        .ok_or(AOCError::NoPathError)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 17;
+
+    type Parsed<'a> = City;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed)?.to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed)?.to_string())
+    }
+}
+
+aoc_common::register_day!(Day17);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn test_parse_input() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let city = parse_input(&input).unwrap();
+        assert_eq!((city.grid.width(), city.grid.height()), (13, 13));
+        assert_eq!(city.grid.get(1, 0), Some(&4));
+
+        assert!(matches!(
+            parse_input("12\n3x"),
+            Err(AOCError::DigitError('x'))
+        ));
+        assert!(matches!(parse_input("12\n3"), Err(AOCError::GridError(_))));
+    }
+
+    #[test]
+    fn test_minimal_heat_loss() {
+        let city: City = "19\n11".parse().unwrap();
+        assert_eq!(city.minimal_heat_loss(Crucible::NORMAL), Some(2));
+        // the ultra crucible can't move 4 blocks
+        assert_eq!(city.minimal_heat_loss(Crucible::ULTRA), None);
+
+        let city: City = "11111".parse().unwrap();
+        assert_eq!(city.minimal_heat_loss(Crucible::NORMAL), None);
+        assert_eq!(city.minimal_heat_loss(Crucible::ULTRA), Some(4));
+    }
+
+    #[test]
+    fn test_process_part1() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part1(&parsed_input).unwrap();
+        assert_eq!(output, 102)
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part2(&parsed_input).unwrap();
+        assert_eq!(output, 94);
+
+        let input = fs::read_to_string("input_test_part2.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part2(&parsed_input).unwrap();
+        assert_eq!(output, 71);
+        assert!(matches!(
+            process_part2(&parse_input("111").unwrap()),
+            Err(AOCError::NoPathError)
+        ));
+    }
+}
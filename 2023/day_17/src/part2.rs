@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_17::Day17, part2);
@@ -0,0 +1,265 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use aoc_common::{Answer, Solution, SolveError};
+use core::{num::ParseIntError, str::FromStr};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AOCError {
+    #[error("Expected a direction, a length and a color")]
+    FormatError,
+    #[error("Unknown direction `{0}`")]
+    DirectionError(String),
+    #[error("Could not parse number: `{0}`")]
+    ParseNumberError(String),
+    #[error("Could not parse color: `{0}`")]
+    ColorError(String),
+    #[error("Line {line}: {error}: `{content}`")]
+    InvalidLineError {
+        line: usize,
+        content: String,
+        error: Box<AOCError>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn offset(self) -> (i64, i64) {
+        match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" => Ok(Direction::Up),
+            "D" => Ok(Direction::Down),
+            "L" => Ok(Direction::Left),
+            "R" => Ok(Direction::Right),
+            _ => Err(AOCError::DirectionError(s.to_string())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+    pub direction: Direction,
+    pub length: u64,
+}
+
+/// A step of the dig plan, with the instruction that is hidden in the color
+/// of the edge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step {
+    pub instruction: Instruction,
+    pub color: Instruction,
+}
+
+/// Decode a color `#XXXXXd` into an instruction, with the first five hex
+/// digits being the length and the last one the direction.
+fn decode(color: &str) -> Result<Instruction, AOCError> {
+    let error = || AOCError::ColorError(color.to_string());
+    let hex = color
+        .strip_prefix("(#")
+        .and_then(|c| c.strip_suffix(')'))
+        .filter(|hex| hex.len() == 6 && hex.is_ascii())
+        .ok_or_else(error)?;
+    let length = u64::from_str_radix(&hex[..5], 16).map_err(|_| error())?;
+    let direction = match &hex[5..] {
+        "0" => Direction::Right,
+        "1" => Direction::Down,
+        "2" => Direction::Left,
+        "3" => Direction::Up,
+        _ => return Err(error()),
+    };
+    Ok(Instruction { direction, length })
+}
+
+impl FromStr for Step {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields = s.split_whitespace();
+        let (Some(direction), Some(length), Some(color), None) =
+            (fields.next(), fields.next(), fields.next(), fields.next())
+        else {
+            return Err(AOCError::FormatError);
+        };
+        let instruction = Instruction {
+            direction: direction.parse()?,
+            length: length
+                .parse()
+                .map_err(|e: ParseIntError| AOCError::ParseNumberError(e.to_string()))?,
+        };
+        Ok(Step {
+            instruction,
+            color: decode(color)?,
+        })
+    }
+}
+
+/// The number of cubic meters of the lagoon dug out along `instructions`,
+/// the trench included. The shoelace formula gives the area `A` of the
+/// polygon through the centers of the trench, with Pick's theorem
+/// `A = i + b / 2 - 1` the lagoon has `i + b = A + b / 2 + 1` cubes.
+pub fn lagoon_volume(instructions: impl IntoIterator<Item = Instruction>) -> u64 {
+    let (mut x, mut y) = (0_i64, 0_i64);
+    let mut twice_area = 0;
+    let mut boundary = 0;
+    for Instruction { direction, length } in instructions {
+        let (dx, dy) = direction.offset();
+        let (next_x, next_y) = (x + dx * length as i64, y + dy * length as i64);
+        twice_area += x * next_y - next_x * y;
+        boundary += length;
+        (x, y) = (next_x, next_y);
+    }
+    (twice_area.unsigned_abs() + boundary) / 2 + 1
+}
+
+/// Parse one step per line, empty lines are skipped.
+pub fn parse_input(input: &str) -> Result<Vec<Step>, AOCError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            line.parse::<Step>()
+                .map_err(|error| AOCError::InvalidLineError {
+                    line: idx + 1,
+                    content: line.to_string(),
+                    error: Box::new(error),
+                })
+        })
+        .collect()
+}
+
+pub fn process_part1(steps: &[Step]) -> u64 {
+    lagoon_volume(steps.iter().map(|step| step.instruction))
+}
+
+pub fn process_part2(steps: &[Step]) -> u64 {
+    lagoon_volume(steps.iter().map(|step| step.color))
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 18;
+
+    type Parsed<'a> = Vec<Step>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed).to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed).to_string())
+    }
+}
+
+aoc_common::register_day!(Day18);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn test_parse_input() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let steps = parse_input(&input).unwrap();
+        assert_eq!(steps.len(), 14);
+        assert_eq!(
+            steps[0],
+            Step {
+                instruction: Instruction {
+                    direction: Direction::Right,
+                    length: 6
+                },
+                color: Instruction {
+                    direction: Direction::Right,
+                    length: 461937
+                },
+            }
+        );
+        assert_eq!(
+            steps[1].color,
+            Instruction {
+                direction: Direction::Down,
+                length: 56407
+            }
+        );
+
+        for (line, expected) in [
+            ("X 6 (#70c710)", "Unknown direction `X`"),
+            ("R x (#70c710)", "Could not parse number"),
+            ("R 6 (#70c71)", "Could not parse color"),
+            ("R 6 (#70c714)", "Could not parse color"),
+            ("R 6 (#70c71g)", "Could not parse color"),
+            ("R 6", "Expected a direction"),
+        ] {
+            let error = parse_input(line).unwrap_err().to_string();
+            assert!(error.starts_with(&format!("Line 1: {expected}")), "{error}");
+        }
+    }
+
+    #[test]
+    fn test_lagoon_volume() {
+        let square = [
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+            Direction::Up,
+        ]
+        .map(|direction| Instruction {
+            direction,
+            length: 2,
+        });
+        assert_eq!(lagoon_volume(square), 9);
+        // the orientation of the loop does not matter
+        assert_eq!(lagoon_volume(square.into_iter().rev()), 9);
+        assert_eq!(lagoon_volume([]), 1);
+    }
+
+    #[test]
+    fn test_process_part1() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part1(&parsed_input);
+        assert_eq!(output, 62)
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part2(&parsed_input);
+        assert_eq!(output, 952408144115)
+    }
+}
@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_18::Day18, part2);
@@ -0,0 +1,427 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use aoc_common::{collections::HashMap, interval::IntervalSet, Answer, Solution, SolveError};
+use core::{ops::Range, str::FromStr};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AOCError {
+    #[error("Could not find the parts after the workflows")]
+    MissingPartsError,
+    #[error("Could not parse workflow: `{0}`")]
+    WorkflowParseError(String),
+    #[error("Could not parse rule: `{0}`")]
+    RuleParseError(String),
+    #[error("Could not parse part: `{0}`")]
+    PartParseError(String),
+    #[error("Workflow `{0}` is defined more than once")]
+    DuplicateWorkflowError(String),
+    #[error("Unknown workflow `{0}`")]
+    UnknownWorkflowError(String),
+    #[error("Workflow `{0}` can send parts back to itself")]
+    WorkflowCycleError(String),
+}
+
+/// The smallest and one more than the largest possible rating.
+pub const RATINGS: Range<u64> = 1..4001;
+
+/// The categories `x`, `m`, `a` and `s` of a rating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl FromStr for Category {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "x" => Ok(Category::X),
+            "m" => Ok(Category::M),
+            "a" => Ok(Category::A),
+            "s" => Ok(Category::S),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    Accept,
+    Reject,
+    Workflow(String),
+}
+
+impl From<&str> for Target {
+    fn from(s: &str) -> Self {
+        match s {
+            "A" => Target::Accept,
+            "R" => Target::Reject,
+            _ => Target::Workflow(s.to_string()),
+        }
+    }
+}
+
+/// A rule sends a part to its target if its rating of `category` is in
+/// `ratings`. Rules without a condition match every part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    condition: Option<(Category, Range<u64>)>,
+    target: Target,
+}
+
+impl Rule {
+    fn matches(&self, part: &Part) -> bool {
+        self.condition
+            .as_ref()
+            .is_none_or(|(category, ratings)| ratings.contains(&part.rating(*category)))
+    }
+}
+
+impl FromStr for Rule {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((condition, target)) = s.split_once(':') else {
+            return Ok(Rule {
+                condition: None,
+                target: s.into(),
+            });
+        };
+        let error = || AOCError::RuleParseError(s.to_string());
+        let operator = condition.find(['<', '>']).ok_or_else(error)?;
+        let category = condition[..operator].parse().map_err(|_| error())?;
+        let value: u64 = condition[operator + 1..].parse().map_err(|_| error())?;
+        let ratings = if condition[operator..].starts_with('<') {
+            0..value
+        } else {
+            value.checked_add(1).ok_or_else(error)?..u64::MAX
+        };
+        Ok(Rule {
+            condition: Some((category, ratings)),
+            target: target.into(),
+        })
+    }
+}
+
+/// The ratings of a part, in the order of [`Category`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Part([u64; 4]);
+
+impl Part {
+    pub fn rating(&self, category: Category) -> u64 {
+        self.0[category as usize]
+    }
+
+    pub fn total_rating(&self) -> u64 {
+        self.0.iter().sum()
+    }
+}
+
+impl FromStr for Part {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || AOCError::PartParseError(s.to_string());
+        let mut ratings = [None; 4];
+        let fields = s
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .ok_or_else(error)?;
+        for field in fields.split(',') {
+            let (category, value) = field.split_once('=').ok_or_else(error)?;
+            let category: Category = category.parse().map_err(|_| error())?;
+            let value = value.parse().map_err(|_| error())?;
+            if ratings[category as usize].replace(value).is_some() {
+                return Err(error());
+            }
+        }
+        let ratings = ratings.map(|rating| rating.ok_or_else(error));
+        let [x, m, a, s] = ratings;
+        Ok(Part([x?, m?, a?, s?]))
+    }
+}
+
+/// The workflows, which are checked to only refer to existing workflows and
+/// to not contain cycles, and the parts to sort.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct System {
+    workflows: HashMap<String, Vec<Rule>>,
+    parts: Vec<Part>,
+}
+
+impl System {
+    pub fn parts(&self) -> &[Part] {
+        &self.parts
+    }
+
+    /// Whether `part` is accepted, starting at the workflow `in`.
+    pub fn accepts(&self, part: &Part) -> bool {
+        let mut workflow = "in";
+        loop {
+            let rule = self.workflows[workflow]
+                .iter()
+                .find(|rule| rule.matches(part));
+            match rule.map(|rule| &rule.target) {
+                Some(Target::Accept) => return true,
+                Some(Target::Reject) | None => return false,
+                Some(Target::Workflow(next)) => workflow = next,
+            }
+        }
+    }
+
+    /// The number of combinations of ratings, with every rating of a
+    /// category in `ratings`, that are accepted.
+    pub fn accepted_combinations(&self, ratings: [IntervalSet<u64>; 4]) -> u64 {
+        self.combinations(&Target::Workflow("in".to_string()), ratings)
+    }
+
+    fn combinations(&self, target: &Target, mut ratings: [IntervalSet<u64>; 4]) -> u64 {
+        let workflow = match target {
+            Target::Accept => {
+                return ratings
+                    .iter()
+                    .map(|set| set.iter().map(|r| r.end - r.start).sum::<u64>())
+                    .product();
+            }
+            Target::Reject => return 0,
+            Target::Workflow(workflow) => workflow,
+        };
+
+        let mut accepted = 0;
+        for rule in &self.workflows[workflow] {
+            let Some((category, condition)) = &rule.condition else {
+                return accepted + self.combinations(&rule.target, ratings);
+            };
+            let condition = IntervalSet::from(condition.clone());
+            let category = *category as usize;
+            let mut matching = ratings.clone();
+            matching[category] = ratings[category].intersection(&condition);
+            ratings[category] = ratings[category].difference(&condition);
+            if !matching[category].is_empty() {
+                accepted += self.combinations(&rule.target, matching);
+            }
+            if ratings[category].is_empty() {
+                break;
+            }
+        }
+        accepted
+    }
+
+    /// Check that every target and `in` exists, and that no workflow can be
+    /// reached from itself, so that sorting a part always terminates.
+    fn validate(&self) -> Result<(), AOCError> {
+        fn visit<'a>(
+            workflows: &'a HashMap<String, Vec<Rule>>,
+            name: &'a str,
+            done: &mut HashMap<&'a str, bool>,
+        ) -> Result<(), AOCError> {
+            match done.get(name) {
+                Some(true) => return Ok(()),
+                Some(false) => return Err(AOCError::WorkflowCycleError(name.to_string())),
+                None => {}
+            }
+            let rules = workflows
+                .get(name)
+                .ok_or_else(|| AOCError::UnknownWorkflowError(name.to_string()))?;
+            done.insert(name, false);
+            for rule in rules {
+                if let Target::Workflow(next) = &rule.target {
+                    visit(workflows, next, done)?;
+                }
+            }
+            done.insert(name, true);
+            Ok(())
+        }
+
+        let mut done = HashMap::new();
+        visit(&self.workflows, "in", &mut done)?;
+        for name in self.workflows.keys() {
+            visit(&self.workflows, name, &mut done)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for System {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (workflows_block, parts_block) = s
+            .trim()
+            .split_once("\n\n")
+            .ok_or(AOCError::MissingPartsError)?;
+
+        let mut workflows = HashMap::new();
+        for line in workflows_block.lines().map(str::trim) {
+            let (name, rules) = line
+                .strip_suffix('}')
+                .and_then(|line| line.split_once('{'))
+                .filter(|(name, _)| !name.is_empty())
+                .ok_or_else(|| AOCError::WorkflowParseError(line.to_string()))?;
+            let rules = rules
+                .split(',')
+                .map(str::parse)
+                .collect::<Result<Vec<Rule>, _>>()?;
+            if workflows.insert(name.to_string(), rules).is_some() {
+                return Err(AOCError::DuplicateWorkflowError(name.to_string()));
+            }
+        }
+        let parts = parts_block
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let system = System { workflows, parts };
+        system.validate()?;
+        Ok(system)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<System, AOCError> {
+    input.parse()
+}
+
+pub fn process_part1(system: &System) -> u64 {
+    system
+        .parts()
+        .iter()
+        .filter(|part| system.accepts(part))
+        .map(Part::total_rating)
+        .sum()
+}
+
+pub fn process_part2(system: &System) -> u64 {
+    system.accepted_combinations(core::array::from_fn(|_| IntervalSet::from(RATINGS)))
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 19;
+
+    type Parsed<'a> = System;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed).to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed).to_string())
+    }
+}
+
+aoc_common::register_day!(Day19);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn test_parse_input() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let system = parse_input(&input).unwrap();
+        assert_eq!(system.workflows.len(), 11);
+        assert_eq!(
+            system.workflows["px"],
+            vec![
+                Rule {
+                    condition: Some((Category::A, 0..2006)),
+                    target: Target::Workflow("qkq".to_string()),
+                },
+                Rule {
+                    condition: Some((Category::M, 2091..u64::MAX)),
+                    target: Target::Accept,
+                },
+                Rule {
+                    condition: None,
+                    target: Target::Workflow("rfg".to_string()),
+                },
+            ]
+        );
+        assert_eq!(system.parts()[0], Part([787, 2655, 1222, 2876]));
+        assert_eq!(system.parts().len(), 5);
+
+        for (input, expected) in [
+            ("in{A}", "Could not find the parts"),
+            (
+                "in{x<1:A,R\n\n{x=1,m=1,a=1,s=1}",
+                "Could not parse workflow",
+            ),
+            ("in{y<1:A,R}\n\n{x=1,m=1,a=1,s=1}", "Could not parse rule"),
+            ("in{A}\n\n{x=1,m=1,a=1}", "Could not parse part"),
+            ("in{A}\n\n{x=1,m=1,a=1,s=1,x=2}", "Could not parse part"),
+            (
+                "in{A}\nin{R}\n\n{x=1,m=1,a=1,s=1}",
+                "Workflow `in` is defined",
+            ),
+            ("in{x<5:ab,R}\n\n{x=1,m=1,a=1,s=1}", "Unknown workflow `ab`"),
+            ("ab{R}\n\n{x=1,m=1,a=1,s=1}", "Unknown workflow `in`"),
+            (
+                "in{x<5:ab,R}\nab{in}\n\n{x=1,m=1,a=1,s=1}",
+                "Workflow `in` can send",
+            ),
+        ] {
+            let error = parse_input(input).unwrap_err().to_string();
+            assert!(error.starts_with(expected), "{error}");
+        }
+    }
+
+    #[test]
+    fn test_accepts() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let system = parse_input(&input).unwrap();
+        let accepted: Vec<bool> = system.parts().iter().map(|p| system.accepts(p)).collect();
+        assert_eq!(accepted, vec![true, false, true, false, true]);
+    }
+
+    #[test]
+    fn test_accepted_combinations() {
+        let system = parse_input("in{x<11:A,m>5:R,A}\n\n{x=1,m=1,a=1,s=1}").unwrap();
+        let ratings = || std::array::from_fn(|_| IntervalSet::from(1..21));
+        // x from 1 to 10, or x from 11 to 20 with m from 1 to 5
+        assert_eq!(
+            system.accepted_combinations(ratings()),
+            10 * 20 * 20 * 20 + 10 * 5 * 20 * 20
+        );
+        let mut limited = ratings();
+        limited[0] = [1..3, 15..16].into_iter().collect();
+        assert_eq!(
+            system.accepted_combinations(limited),
+            2 * 20 * 20 * 20 + 5 * 20 * 20
+        );
+    }
+
+    #[test]
+    fn test_process_part1() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part1(&parsed_input);
+        assert_eq!(output, 19114)
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part2(&parsed_input);
+        assert_eq!(output, 167409079868000)
+    }
+}
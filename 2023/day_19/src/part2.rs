@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_19::Day19, part2);
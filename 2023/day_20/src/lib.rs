@@ -0,0 +1,429 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    collections::VecDeque,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use aoc_common::{collections::HashMap, math::checked_lcm, Answer, Solution, SolveError};
+use core::str::FromStr;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AOCError {
+    #[error("Could not parse module: `{0}`")]
+    ModuleParseError(String),
+    #[error("Module `{0}` is defined more than once")]
+    DuplicateModuleError(String),
+    #[error("Could not find the broadcaster")]
+    MissingBroadcasterError,
+    #[error("No module sends pulses to `rx`")]
+    MissingRxError,
+    #[error("`rx` needs to receive pulses from exactly one conjunction")]
+    RxFeederError,
+    #[error("`{0}` does not send high pulses periodically")]
+    NotPeriodicError(String),
+    #[error("The number of button presses overflows")]
+    OverflowError,
+}
+
+/// The number of button presses after which the search for the periods of
+/// the modules feeding `rx` gives up.
+pub const MAX_PRESSES: usize = 100_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleKind {
+    Broadcaster,
+    FlipFlop,
+    Conjunction,
+    /// A module that only receives pulses, e.g. `rx`
+    Untyped,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Pulse {
+    pub from: usize,
+    pub to: usize,
+    pub high: bool,
+}
+
+/// The state of the flip-flops, and the last pulse each conjunction received
+/// from each of its inputs, in the order of [`Network::inputs`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct State {
+    on: Vec<bool>,
+    memory: Vec<Vec<bool>>,
+}
+
+/// The modules and their connections. Modules are referred to by their
+/// position in `names`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Network {
+    names: Vec<String>,
+    kinds: Vec<ModuleKind>,
+    outputs: Vec<Vec<usize>>,
+    inputs: Vec<Vec<usize>>,
+    index: HashMap<String, usize>,
+    broadcaster: usize,
+}
+
+impl Network {
+    pub fn module(&self, name: &str) -> Option<usize> {
+        self.index.get(name).copied()
+    }
+
+    pub fn name(&self, module: usize) -> &str {
+        &self.names[module]
+    }
+
+    pub fn kind(&self, module: usize) -> ModuleKind {
+        self.kinds[module]
+    }
+
+    pub fn outputs(&self, module: usize) -> &[usize] {
+        &self.outputs[module]
+    }
+
+    pub fn inputs(&self, module: usize) -> &[usize] {
+        &self.inputs[module]
+    }
+
+    fn add(&mut self, name: &str, kind: ModuleKind) -> usize {
+        let module = self.names.len();
+        self.names.push(name.to_string());
+        self.kinds.push(kind);
+        self.outputs.push(Vec::new());
+        self.inputs.push(Vec::new());
+        self.index.insert(name.to_string(), module);
+        module
+    }
+
+    /// All flip-flops off, and all conjunctions remembering low pulses.
+    pub fn initial_state(&self) -> State {
+        State {
+            on: vec![false; self.names.len()],
+            memory: self.inputs.iter().map(|i| vec![false; i.len()]).collect(),
+        }
+    }
+
+    /// Push the button once and process pulses in the order they are sent,
+    /// until no more pulses are sent. `on_pulse` is called for every pulse,
+    /// the one of the button has the broadcaster as its source.
+    pub fn press(&self, state: &mut State, mut on_pulse: impl FnMut(&Pulse)) {
+        let mut queue = VecDeque::from([Pulse {
+            from: self.broadcaster,
+            to: self.broadcaster,
+            high: false,
+        }]);
+        while let Some(pulse) = queue.pop_front() {
+            on_pulse(&pulse);
+            let module = pulse.to;
+            let high = match self.kinds[module] {
+                ModuleKind::Broadcaster => pulse.high,
+                ModuleKind::FlipFlop if pulse.high => continue,
+                ModuleKind::FlipFlop => {
+                    state.on[module] = !state.on[module];
+                    state.on[module]
+                }
+                ModuleKind::Conjunction => {
+                    let memory = &mut state.memory[module];
+                    for (slot, input) in self.inputs[module].iter().enumerate() {
+                        if *input == pulse.from {
+                            memory[slot] = pulse.high;
+                        }
+                    }
+                    !memory.iter().all(|high| *high)
+                }
+                ModuleKind::Untyped => continue,
+            };
+            queue.extend(self.outputs[module].iter().map(|&to| Pulse {
+                from: module,
+                to,
+                high,
+            }));
+        }
+    }
+
+    /// The conjunction that is the only module sending pulses to `rx`. It
+    /// sends a low pulse once the last pulses of all of its inputs were high.
+    pub fn rx_feeder(&self) -> Result<usize, AOCError> {
+        let rx = self.module("rx").ok_or(AOCError::MissingRxError)?;
+        match self.inputs[rx][..] {
+            [feeder] if self.kinds[feeder] == ModuleKind::Conjunction => Ok(feeder),
+            _ => Err(AOCError::RxFeederError),
+        }
+    }
+}
+
+/// The kind and the name of a module, e.g. `%a`.
+fn parse_module(s: &str) -> (ModuleKind, &str) {
+    if let Some(name) = s.strip_prefix('%') {
+        (ModuleKind::FlipFlop, name)
+    } else if let Some(name) = s.strip_prefix('&') {
+        (ModuleKind::Conjunction, name)
+    } else if s == "broadcaster" {
+        (ModuleKind::Broadcaster, s)
+    } else {
+        (ModuleKind::Untyped, s)
+    }
+}
+
+impl FromStr for Network {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut modules = Vec::new();
+        for line in s.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let (module, outputs) = line
+                .split_once(" -> ")
+                .ok_or_else(|| AOCError::ModuleParseError(line.to_string()))?;
+            let (kind, name) = parse_module(module);
+            if kind == ModuleKind::Untyped || name.is_empty() {
+                return Err(AOCError::ModuleParseError(line.to_string()));
+            }
+            let outputs: Vec<&str> = outputs.split(',').map(str::trim).collect();
+            modules.push((kind, name, outputs));
+        }
+
+        let mut network = Network {
+            names: Vec::new(),
+            kinds: Vec::new(),
+            outputs: Vec::new(),
+            inputs: Vec::new(),
+            index: HashMap::new(),
+            broadcaster: 0,
+        };
+        for (kind, name, _) in &modules {
+            if network.index.contains_key(*name) {
+                return Err(AOCError::DuplicateModuleError(name.to_string()));
+            }
+            network.add(name, *kind);
+        }
+        network.broadcaster = network
+            .module("broadcaster")
+            .ok_or(AOCError::MissingBroadcasterError)?;
+        for (_, name, outputs) in &modules {
+            let from = network.index[*name];
+            for output in outputs {
+                let to = match network.module(output) {
+                    Some(to) => to,
+                    None => network.add(output, ModuleKind::Untyped),
+                };
+                network.outputs[from].push(to);
+                network.inputs[to].push(from);
+            }
+        }
+        Ok(network)
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Network, AOCError> {
+    input.parse()
+}
+
+/// The product of the number of low and high pulses sent during 1000
+/// button presses.
+pub fn process_part1(network: &Network) -> usize {
+    let mut state = network.initial_state();
+    let (mut low, mut high) = (0, 0);
+    for _ in 0..1000 {
+        network.press(&mut state, |pulse| {
+            if pulse.high {
+                high += 1;
+            } else {
+                low += 1;
+            }
+        });
+    }
+    low * high
+}
+
+/// The number of presses until `rx` receives a low pulse. This relies on
+/// the structure of the puzzle inputs: `rx` is fed by a single conjunction,
+/// and each of its inputs sends it a high pulse every `p` presses, so all of
+/// them do so during the same press after the least common multiple of the
+/// periods.
+pub fn process_part2(network: &Network) -> Result<usize, AOCError> {
+    let feeder = network.rx_feeder()?;
+    let inputs = network.inputs(feeder);
+    let mut high_presses = vec![Vec::new(); inputs.len()];
+    let mut state = network.initial_state();
+    for press in 1..=MAX_PRESSES {
+        network.press(&mut state, |pulse| {
+            if pulse.to == feeder && pulse.high {
+                let slot = inputs.iter().position(|i| *i == pulse.from).unwrap();
+                if high_presses[slot].last() != Some(&press) {
+                    high_presses[slot].push(press);
+                }
+            }
+        });
+        if high_presses.iter().all(|presses| presses.len() >= 2) {
+            break;
+        }
+    }
+
+    inputs
+        .iter()
+        .zip(&high_presses)
+        .try_fold(1, |presses, (&input, high)| {
+            let period = match high[..] {
+                [first, second, ..] if second == 2 * first => first,
+                _ => return Err(AOCError::NotPeriodicError(network.name(input).to_string())),
+            };
+            checked_lcm(presses, period).ok_or(AOCError::OverflowError)
+        })
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 20;
+
+    type Parsed<'a> = Network;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed).to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed)?.to_string())
+    }
+}
+
+aoc_common::register_day!(Day20);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    const WITH_OUTPUT: &str = "\
+broadcaster -> a
+%a -> inv, con
+&inv -> b
+%b -> con
+&con -> output";
+
+    #[test]
+    fn test_parse_input() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let network = parse_input(&input).unwrap();
+        let inv = network.module("inv").unwrap();
+        assert_eq!(network.kind(inv), ModuleKind::Conjunction);
+        assert_eq!(network.inputs(inv), &[network.module("c").unwrap()]);
+        assert_eq!(network.outputs(inv), &[network.module("a").unwrap()]);
+        assert_eq!(
+            network.kind(network.module("a").unwrap()),
+            ModuleKind::FlipFlop
+        );
+
+        let network = parse_input(WITH_OUTPUT).unwrap();
+        let output = network.module("output").unwrap();
+        assert_eq!(network.kind(output), ModuleKind::Untyped);
+        assert_eq!(network.name(network.inputs(output)[0]), "con");
+
+        for (input, expected) in [
+            ("broadcaster > a", "Could not parse module"),
+            ("a -> b", "Could not parse module"),
+            ("% -> b", "Could not parse module"),
+            ("%a -> b", "Could not find the broadcaster"),
+            (
+                "broadcaster -> a\n%a -> a\n&a -> a",
+                "Module `a` is defined",
+            ),
+        ] {
+            let error = parse_input(input).unwrap_err().to_string();
+            assert!(error.starts_with(expected), "{error}");
+        }
+    }
+
+    #[test]
+    fn test_press() {
+        let network = parse_input(WITH_OUTPUT).unwrap();
+        let mut state = network.initial_state();
+        let mut pulses = Vec::new();
+        network.press(&mut state, |pulse| {
+            pulses.push(format!(
+                "{} -{}-> {}",
+                network.name(pulse.from),
+                if pulse.high { "high" } else { "low" },
+                network.name(pulse.to)
+            ))
+        });
+        assert_eq!(
+            pulses,
+            vec![
+                "broadcaster -low-> broadcaster",
+                "broadcaster -low-> a",
+                "a -high-> inv",
+                "a -high-> con",
+                "inv -low-> b",
+                "con -high-> output",
+                "b -high-> con",
+                "con -low-> output",
+            ]
+        );
+
+        // the state is back to the initial one after 4 presses
+        for _ in 0..3 {
+            network.press(&mut state, |_| {});
+        }
+        assert_eq!(state, network.initial_state());
+    }
+
+    #[test]
+    fn test_process_part1() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part1(&parsed_input);
+        assert_eq!(output, 32000000);
+
+        let parsed_input = parse_input(WITH_OUTPUT).unwrap();
+        assert_eq!(process_part1(&parsed_input), 11687500);
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input = fs::read_to_string("input_test_part2.txt").expect("Could not read the file");
+        let network = parse_input(&input).unwrap();
+        let feeder = network.rx_feeder().unwrap();
+        assert_eq!(network.name(feeder), "fd");
+        assert_eq!(process_part2(&network).unwrap(), 4);
+
+        // the shortcut agrees with pressing the button until `rx` gets a
+        // low pulse
+        let rx = network.module("rx").unwrap();
+        let mut state = network.initial_state();
+        let presses = (1..)
+            .find(|_| {
+                let mut low = false;
+                network.press(&mut state, |pulse| low |= pulse.to == rx && !pulse.high);
+                low
+            })
+            .unwrap();
+        assert_eq!(presses, 4);
+
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        assert!(matches!(
+            process_part2(&parse_input(&input).unwrap()),
+            Err(AOCError::MissingRxError)
+        ));
+        assert!(matches!(
+            process_part2(&parse_input("broadcaster -> a, rx\n%a -> rx").unwrap()),
+            Err(AOCError::RxFeederError)
+        ));
+        // `a` sends a high pulse at every odd press
+        assert!(matches!(
+            process_part2(&parse_input("broadcaster -> a\n%a -> fd\n&fd -> rx").unwrap()),
+            Err(AOCError::NotPeriodicError(name)) if name == "a"
+        ));
+    }
+}
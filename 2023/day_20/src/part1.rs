@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_20::Day20, part1);
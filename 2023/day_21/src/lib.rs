@@ -0,0 +1,256 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec, vec::Vec};
+use aoc_common::{
+    collections::HashSet,
+    grid::{Grid, GridError},
+    Answer, Solution, SolveError,
+};
+use core::str::FromStr;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AOCError {
+    #[error("Could not parse the garden: {0}")]
+    GridError(#[from] GridError),
+    #[error("Unknown tile `{0}`")]
+    TileError(char),
+    #[error("Could not find the starting position")]
+    MissingStartError,
+    #[error("The garden has more than one starting position")]
+    MultipleStartsError,
+    #[error("Extrapolating needs a square garden with the start in its center")]
+    ExtrapolationError,
+}
+
+/// The number of steps of part 2.
+pub const STEPS_PART2: usize = 26501365;
+
+/// The garden plots (`true`) and rocks (`false`) of the map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Garden {
+    grid: Grid<bool>,
+    start: (i32, i32),
+}
+
+impl Garden {
+    pub fn start(&self) -> (i32, i32) {
+        self.start
+    }
+
+    fn is_plot(&self, (x, y): (i32, i32), infinite: bool) -> bool {
+        let plot = if infinite {
+            let (width, height) = (self.grid.width() as i32, self.grid.height() as i32);
+            self.grid.get(x.rem_euclid(width), y.rem_euclid(height))
+        } else {
+            self.grid.get(x, y)
+        };
+        plot.is_some_and(|&plot| plot)
+    }
+
+    /// The number of plots the elf can be at after exactly `steps` steps. It
+    /// can go back and forth, so these are the plots reachable in at most
+    /// `steps` steps with the same parity as `steps`. If `infinite`, the map
+    /// is repeated in all directions.
+    pub fn reachable(&self, steps: usize, infinite: bool) -> usize {
+        let mut seen: HashSet<_> = HashSet::from([self.start]);
+        let mut frontier = vec![self.start];
+        let mut count = usize::from(steps.is_multiple_of(2));
+        for step in 1..=steps {
+            let mut next = Vec::new();
+            for (x, y) in frontier {
+                for (dx, dy) in [(0, -1), (-1, 0), (1, 0), (0, 1)] {
+                    let position = (x + dx, y + dy);
+                    if self.is_plot(position, infinite) && seen.insert(position) {
+                        next.push(position);
+                    }
+                }
+            }
+            if step % 2 == steps % 2 {
+                count += next.len();
+            }
+            frontier = next;
+        }
+        count
+    }
+
+    /// The number of plots reachable in exactly `steps` steps on the infinite
+    /// map, extrapolated from the counts after `r`, `r + n` and `r + 2n`
+    /// steps, with `n` the size of the map and `r = steps % n`.
+    ///
+    /// The inputs have an empty row and column through the start and an
+    /// empty border, so every `n` steps the reachable area grows by another
+    /// ring of copies of the map, and the count is a quadratic polynomial in
+    /// `steps / n`. This does not hold for every map, e.g. not for the
+    /// example.
+    pub fn reachable_extrapolated(&self, steps: usize) -> Result<usize, AOCError> {
+        let size = self.grid.width();
+        let center = (size / 2) as i32;
+        if size != self.grid.height() || self.start != (center, center) {
+            return Err(AOCError::ExtrapolationError);
+        }
+        let (remainder, k) = (steps % size, steps / size);
+        let [y0, y1, y2] = [0, 1, 2].map(|i| self.reachable(remainder + i * size, true) as i128);
+        // Newton's forward differences of the samples
+        let (d1, d2) = (y1 - y0, y2 - 2 * y1 + y0);
+        let k = k as i128;
+        Ok((y0 + k * d1 + k * (k - 1) / 2 * d2) as usize)
+    }
+}
+
+impl FromStr for Garden {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid: Grid<char> = s.trim().parse()?;
+        let mut start = None;
+        let mut rows = Vec::new();
+        for (y, row) in grid.rows().enumerate() {
+            let mut plots = Vec::new();
+            for (x, &tile) in row.iter().enumerate() {
+                match tile {
+                    'S' if start.is_some() => return Err(AOCError::MultipleStartsError),
+                    'S' => start = Some((x as i32, y as i32)),
+                    '.' | '#' => {}
+                    _ => return Err(AOCError::TileError(tile)),
+                }
+                plots.push(tile != '#');
+            }
+            rows.push(plots);
+        }
+        Ok(Garden {
+            grid: Grid::from_rows(rows)?,
+            start: start.ok_or(AOCError::MissingStartError)?,
+        })
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Garden, AOCError> {
+    input.parse()
+}
+
+pub fn process_part1(garden: &Garden) -> usize {
+    garden.reachable(64, false)
+}
+
+pub fn process_part2(garden: &Garden) -> Result<usize, AOCError> {
+    garden.reachable_extrapolated(STEPS_PART2)
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 21;
+
+    type Parsed<'a> = Garden;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed).to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed)?.to_string())
+    }
+}
+
+aoc_common::register_day!(Day21);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    /// A map with the structure of the puzzle inputs.
+    const OPEN_GARDEN: &str = "\
+.........
+.#.#..#..
+..#...##.
+.#.....#.
+....S....
+.##...#..
+..#...#..
+.#.#...#.
+.........";
+
+    #[test]
+    fn test_parse_input() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let garden = parse_input(&input).unwrap();
+        assert_eq!(garden.start(), (5, 5));
+        assert_eq!(garden.grid.get(5, 1), Some(&false));
+        assert_eq!(garden.grid.get(5, 5), Some(&true));
+
+        assert!(matches!(
+            parse_input("S.\n.x"),
+            Err(AOCError::TileError('x'))
+        ));
+        assert!(matches!(
+            parse_input("..\n.#"),
+            Err(AOCError::MissingStartError)
+        ));
+        assert!(matches!(
+            parse_input("S.\n.S"),
+            Err(AOCError::MultipleStartsError)
+        ));
+        assert!(matches!(parse_input("S.\n."), Err(AOCError::GridError(_))));
+    }
+
+    #[test]
+    fn test_reachable() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let garden = parse_input(&input).unwrap();
+        assert_eq!(garden.reachable(0, false), 1);
+        assert_eq!(garden.reachable(1, false), 2);
+        assert_eq!(garden.reachable(2, false), 4);
+        assert_eq!(garden.reachable(3, false), 6);
+        assert_eq!(garden.reachable(6, false), 16);
+    }
+
+    #[test]
+    fn test_reachable_infinite() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let garden = parse_input(&input).unwrap();
+        for (steps, plots) in [(6, 16), (10, 50), (50, 1594), (100, 6536), (500, 167004)] {
+            assert_eq!(garden.reachable(steps, true), plots);
+        }
+    }
+
+    #[test]
+    fn test_reachable_extrapolated() {
+        let garden = parse_input(OPEN_GARDEN).unwrap();
+        for steps in [22, 40, 49, 67, 100, 131] {
+            assert_eq!(
+                garden.reachable_extrapolated(steps).unwrap(),
+                garden.reachable(steps, true),
+                "{steps}"
+            );
+        }
+
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let garden = parse_input(&input).unwrap();
+        assert_ne!(
+            garden.reachable_extrapolated(100).unwrap(),
+            garden.reachable(100, true)
+        );
+        let garden = parse_input("S..\n...\n...").unwrap();
+        assert!(matches!(
+            garden.reachable_extrapolated(100),
+            Err(AOCError::ExtrapolationError)
+        ));
+    }
+
+    #[test]
+    fn test_process_part1() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part1(&parsed_input);
+        assert_eq!(output, 42)
+    }
+}
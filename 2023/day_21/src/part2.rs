@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_21::Day21, part2);
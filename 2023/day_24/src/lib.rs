@@ -0,0 +1,377 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use aoc_common::{geometry::Point3, Answer, Solution, SolveError};
+use core::{num::ParseIntError, ops::RangeInclusive, str::FromStr};
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::Zero;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AOCError {
+    #[error("Expected a position and a velocity separated by `@`")]
+    FormatError,
+    #[error("Expected three coordinates: `{0}`")]
+    CoordinatesError(String),
+    #[error("Could not parse number: `{0}`")]
+    ParseNumberError(String),
+    #[error("Line {line}: {error}: `{content}`")]
+    InvalidLineError {
+        line: usize,
+        content: String,
+        error: Box<AOCError>,
+    },
+    #[error("No rock thrown from an integer position hits every hailstone")]
+    NoRockError,
+}
+
+/// The test area of part 1, for both the X and the Y coordinates.
+pub const AREA_PART1: RangeInclusive<i64> = 200000000000000..=400000000000000;
+
+/// A hailstone, or the rock, that moves by `velocity` every nanosecond.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hailstone {
+    pub position: Point3,
+    pub velocity: Point3,
+}
+
+fn parse_point(s: &str) -> Result<Point3, AOCError> {
+    let coordinates = s
+        .split(',')
+        .map(|c| {
+            c.trim()
+                .parse()
+                .map_err(|e: ParseIntError| AOCError::ParseNumberError(e.to_string()))
+        })
+        .collect::<Result<Vec<i64>, _>>()?;
+    match coordinates[..] {
+        [x, y, z] => Ok(Point3::new(x, y, z)),
+        _ => Err(AOCError::CoordinatesError(s.trim().to_string())),
+    }
+}
+
+impl FromStr for Hailstone {
+    type Err = AOCError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (position, velocity) = s.split_once('@').ok_or(AOCError::FormatError)?;
+        Ok(Hailstone {
+            position: parse_point(position)?,
+            velocity: parse_point(velocity)?,
+        })
+    }
+}
+
+fn coordinates(point: Point3) -> [i128; 3] {
+    [point.x, point.y, point.z].map(i128::from)
+}
+
+fn cross(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn sub(a: [i128; 3], b: [i128; 3]) -> [i128; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+impl Hailstone {
+    /// Whether the paths of the hailstones cross inside `area` in the future
+    /// of both, ignoring the Z axis. The crossing is solved with Cramer's
+    /// rule, the coordinates of the inputs are small enough to compare the
+    /// fractions exactly in `i128`.
+    pub fn crosses_within(&self, other: &Hailstone, area: &RangeInclusive<i64>) -> bool {
+        let [px, py, _] = coordinates(self.position);
+        let [vx, vy, _] = coordinates(self.velocity);
+        let [qx, qy, _] = coordinates(other.position);
+        let [wx, wy, _] = coordinates(other.velocity);
+        let mut det = vx * wy - vy * wx;
+        // parallel paths don't cross, the inputs have no identical ones
+        if det == 0 {
+            return false;
+        }
+        // the times are `t / det` and `s / det`
+        let (dx, dy) = (qx - px, qy - py);
+        let (mut t, mut s) = (dx * wy - dy * wx, dx * vy - dy * vx);
+        if det < 0 {
+            (det, t, s) = (-det, -t, -s);
+        }
+        let (min, max) = (i128::from(*area.start()), i128::from(*area.end()));
+        let inside = |x: i128| min * det <= x && x <= max * det;
+        t >= 0 && s >= 0 && inside(px * det + vx * t) && inside(py * det + vy * t)
+    }
+
+    /// Whether a rock thrown like `self` hits `hailstone` at some time that
+    /// is not in the past.
+    pub fn hits(&self, hailstone: &Hailstone) -> bool {
+        let distance = sub(coordinates(hailstone.position), coordinates(self.position));
+        let closing = sub(coordinates(self.velocity), coordinates(hailstone.velocity));
+        let towards: i128 = (0..3).map(|i| distance[i] * closing[i]).sum();
+        match closing {
+            [0, 0, 0] => distance == [0, 0, 0],
+            _ => cross(distance, closing) == [0, 0, 0] && towards >= 0,
+        }
+    }
+}
+
+/// The number of pairs of hailstones whose paths cross within `area`.
+pub fn crossings(hailstones: &[Hailstone], area: &RangeInclusive<i64>) -> usize {
+    hailstones
+        .iter()
+        .enumerate()
+        .map(|(i, a)| {
+            hailstones[i + 1..]
+                .iter()
+                .filter(|b| a.crosses_within(b, area))
+                .count()
+        })
+        .sum()
+}
+
+/// Solve the augmented matrix `rows` with Gaussian elimination, or `None` if
+/// the system is singular.
+pub fn solve_linear(mut rows: Vec<Vec<BigRational>>) -> Option<Vec<BigRational>> {
+    let n = rows.len();
+    for column in 0..n {
+        let pivot = (column..n).find(|&row| !rows[row][column].is_zero())?;
+        rows.swap(column, pivot);
+        let pivot_row = rows[column].clone();
+        for (row, values) in rows.iter_mut().enumerate() {
+            if row == column || values[column].is_zero() {
+                continue;
+            }
+            let factor = &values[column] / &pivot_row[column];
+            for (value, pivot_value) in values.iter_mut().zip(&pivot_row) {
+                *value -= &factor * pivot_value;
+            }
+        }
+    }
+    Some(
+        rows.into_iter()
+            .enumerate()
+            .map(|(i, row)| &row[n] / &row[i])
+            .collect(),
+    )
+}
+
+/// The linear equations of the rock `(P, V)` from three hailstones. A rock
+/// hits hailstone `k` iff `(P - p_k) × (V - v_k) = 0`, the difference of the
+/// equations of two hailstones cancels the quadratic `P × V`:
+/// `P × (v_j - v_0) + (p_j - p_0) × V = p_j × v_j - p_0 × v_0`.
+fn rock_equations(hailstones: &[Hailstone]) -> Vec<Vec<BigRational>> {
+    let [p0, v0] = [hailstones[0].position, hailstones[0].velocity].map(coordinates);
+    let mut rows = Vec::new();
+    for hailstone in &hailstones[1..3] {
+        let [p, v] = [hailstone.position, hailstone.velocity].map(coordinates);
+        let (dp, dv) = (sub(p, p0), sub(v, v0));
+        let right = sub(cross(p, v), cross(p0, v0));
+        for (i, right) in right.into_iter().enumerate() {
+            let (a, b) = ((i + 1) % 3, (i + 2) % 3);
+            // the unknowns are the coordinates of P and then of V
+            let mut row = [0; 7];
+            row[a] = dv[b];
+            row[b] = -dv[a];
+            row[3 + b] = dp[a];
+            row[3 + a] = -dp[b];
+            row[6] = right;
+            rows.push(
+                row.map(|x| BigRational::from_integer(BigInt::from(x)))
+                    .to_vec(),
+            );
+        }
+    }
+    rows
+}
+
+fn to_i64(value: &BigRational) -> Option<i64> {
+    value
+        .is_integer()
+        .then(|| i64::try_from(&value.to_integer()).ok())
+        .flatten()
+}
+
+/// The rock that hits every hailstone. Three hailstones whose equations are
+/// independent determine it, the other hailstones only check it.
+pub fn rock(hailstones: &[Hailstone]) -> Result<Hailstone, AOCError> {
+    let solution = hailstones
+        .windows(3)
+        .find_map(|three| solve_linear(rock_equations(three)))
+        .ok_or(AOCError::NoRockError)?;
+    let values = solution
+        .iter()
+        .map(to_i64)
+        .collect::<Option<Vec<_>>>()
+        .ok_or(AOCError::NoRockError)?;
+    let rock = Hailstone {
+        position: Point3::new(values[0], values[1], values[2]),
+        velocity: Point3::new(values[3], values[4], values[5]),
+    };
+    match hailstones.iter().all(|hailstone| rock.hits(hailstone)) {
+        true => Ok(rock),
+        false => Err(AOCError::NoRockError),
+    }
+}
+
+/// Parse one hailstone per line, empty lines are skipped.
+pub fn parse_input(input: &str) -> Result<Vec<Hailstone>, AOCError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            line.parse::<Hailstone>()
+                .map_err(|error| AOCError::InvalidLineError {
+                    line: idx + 1,
+                    content: line.to_string(),
+                    error: Box::new(error),
+                })
+        })
+        .collect()
+}
+
+pub fn process_part1(hailstones: &[Hailstone]) -> usize {
+    crossings(hailstones, &AREA_PART1)
+}
+
+pub fn process_part2(hailstones: &[Hailstone]) -> Result<i64, AOCError> {
+    let rock = rock(hailstones)?;
+    Ok(rock.position.x + rock.position.y + rock.position.z)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 24;
+
+    type Parsed<'a> = Vec<Hailstone>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input)?)
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed).to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed)?.to_string())
+    }
+}
+
+aoc_common::register_day!(Day24);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    fn hailstone(position: (i64, i64, i64), velocity: (i64, i64, i64)) -> Hailstone {
+        Hailstone {
+            position: Point3::new(position.0, position.1, position.2),
+            velocity: Point3::new(velocity.0, velocity.1, velocity.2),
+        }
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let hailstones = parse_input(&input).unwrap();
+        assert_eq!(hailstones.len(), 5);
+        assert_eq!(hailstones[0], hailstone((19, 13, 30), (-2, 1, -2)));
+        assert_eq!(hailstones[4], hailstone((20, 19, 15), (1, -5, -3)));
+
+        for (line, expected) in [
+            ("19, 13, 30 -2, 1, -2", "Expected a position"),
+            ("19, 13 @ -2, 1, -2", "Expected three coordinates"),
+            ("19, 13, x @ -2, 1, -2", "Could not parse number"),
+        ] {
+            let error = parse_input(line).unwrap_err().to_string();
+            assert!(error.starts_with(&format!("Line 1: {expected}")), "{error}");
+        }
+    }
+
+    #[test]
+    fn test_crossings() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let hailstones = parse_input(&input).unwrap();
+        // inside the area, outside of it, and in the past of the first one
+        assert!(hailstones[0].crosses_within(&hailstones[1], &(7..=27)));
+        assert!(!hailstones[0].crosses_within(&hailstones[3], &(7..=27)));
+        assert!(!hailstones[0].crosses_within(&hailstones[4], &(7..=27)));
+        // parallel
+        assert!(!hailstones[1].crosses_within(&hailstones[2], &(7..=27)));
+        assert_eq!(crossings(&hailstones, &(7..=27)), 2);
+    }
+
+    #[test]
+    fn test_rock() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let hailstones = parse_input(&input).unwrap();
+        assert_eq!(
+            rock(&hailstones).unwrap(),
+            hailstone((24, 13, 10), (-3, 1, 2))
+        );
+
+        let mut missed = hailstones.clone();
+        missed.push(hailstone((0, 0, 0), (1, 1, 1)));
+        assert!(matches!(rock(&missed), Err(AOCError::NoRockError)));
+        assert!(matches!(rock(&hailstones[..2]), Err(AOCError::NoRockError)));
+    }
+
+    #[test]
+    fn test_rock_large() {
+        // coordinates of the size of the real input, the fractions of the
+        // elimination don't fit in `i128`
+        let thrown = hailstone(
+            (287430900705823, 451620998712421, 260730677041648),
+            (-42, -274, 66),
+        );
+        let hailstones: Vec<Hailstone> = [
+            (719465133896, (47, -103, 181)),
+            (291475310753, (-191, 414, -28)),
+            (433940612101, (320, -87, 205)),
+            (905375521421, (-13, -344, -212)),
+        ]
+        .into_iter()
+        .map(|(time, (vx, vy, vz))| {
+            let velocity = Point3::new(vx, vy, vz);
+            let offset = thrown.velocity - velocity;
+            let position = Point3::new(
+                thrown.position.x + time * offset.x,
+                thrown.position.y + time * offset.y,
+                thrown.position.z + time * offset.z,
+            );
+            Hailstone { position, velocity }
+        })
+        .collect();
+        assert_eq!(rock(&hailstones).unwrap(), thrown);
+    }
+
+    #[test]
+    fn test_process_part1() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        // the example has no crossings in the area of the real input
+        let output = process_part1(&parsed_input);
+        assert_eq!(output, 0)
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input).unwrap();
+        let output = process_part2(&parsed_input).unwrap();
+        assert_eq!(output, 47)
+    }
+}
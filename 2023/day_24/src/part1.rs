@@ -0,0 +1 @@
+aoc_common::aoc_main!(aoc2023_day_24::Day24, part1);
@@ -0,0 +1,52 @@
+use std::{fs, hint::black_box, path::Path};
+
+use aoc::default_input;
+use aoc2023_day_01::{parse, process_part1, process_part1_bytes, DigitMatcher};
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+
+/// Copies of the part 1 input, this many, so that the scan dominates.
+const COPIES: usize = 100;
+
+/// Part 1 by matching every character, like `DigitMatcher` does with words.
+fn process_part1_chars(input: &[&str]) -> u32 {
+    let matcher = DigitMatcher::digits_only();
+    input
+        .iter()
+        .filter_map(|line| {
+            let mut digits = matcher.digits(line);
+            let first = digits.next()?;
+            Some(first * 10 + digits.next_back().unwrap_or(first))
+        })
+        .sum()
+}
+
+/// Compare finding the digits of part 1 by characters with the byte scans,
+/// on the lines and on the whole input.
+fn part1(c: &mut Criterion) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(default_input(2023, 1, Some(1)));
+    let Ok(input) = fs::read_to_string(&path) else {
+        eprintln!("Skipping day 1, could not read `{}`", path.display());
+        return;
+    };
+    let input = input.repeat(COPIES);
+    let lines = parse(&input);
+
+    let mut group = c.benchmark_group("2023_day_01_part1");
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("chars", |b| {
+        b.iter(|| process_part1_chars(black_box(&lines)))
+    });
+    group.bench_function("bytes", |b| b.iter(|| process_part1(black_box(&lines))));
+    group.bench_function("parse_and_bytes", |b| {
+        b.iter(|| process_part1(&parse(black_box(&input))))
+    });
+    group.bench_function("memchr", |b| {
+        b.iter(|| process_part1_bytes(black_box(input.as_bytes())))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, part1);
+criterion_main!(benches);
@@ -0,0 +1,11 @@
+use aoc_bench::bench_solution;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn days(c: &mut Criterion) {
+    for day in aoc_days::days() {
+        bench_solution(c, day);
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,34 @@
+use std::{fs, hint::black_box, path::Path};
+
+use aoc::default_input;
+use aoc_days::RegisteredDay;
+use criterion::Criterion;
+
+/// Benchmark parsing and both parts of a day on its real input. Days without
+/// a downloaded input, or with an input they can't parse, are skipped.
+pub fn bench_solution(c: &mut Criterion, registered: &RegisteredDay) {
+    let (year, day) = (registered.year, registered.day);
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(default_input(year, day, None));
+    let Ok(input) = fs::read_to_string(&path) else {
+        eprintln!(
+            "Skipping day {day} of {year}, could not read `{}`",
+            path.display()
+        );
+        return;
+    };
+    let parsed = match registered.parse(&input) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("Skipping day {day} of {year}, could not parse the input: {e}");
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("{year}_day_{day:02}"));
+    group.bench_function("parse", |b| b.iter(|| registered.parse(black_box(&input))));
+    group.bench_function("part1", |b| b.iter(|| black_box(&parsed).part1()));
+    group.bench_function("part2", |b| b.iter(|| black_box(&parsed).part2()));
+    group.finish();
+}
@@ -0,0 +1,8 @@
+//! Hash maps and sets of `std`, or of hashbrown without it. Both use the
+//! default hasher of their crate, so the days only depend on the API they
+//! share.
+
+#[cfg(not(feature = "std"))]
+pub use hashbrown::{hash_map, hash_set, HashMap, HashSet};
+#[cfg(feature = "std")]
+pub use std::collections::{hash_map, hash_set, HashMap, HashSet};
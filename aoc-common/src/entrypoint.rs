@@ -0,0 +1,113 @@
+use std::{env, fmt::Display, process, time::Instant};
+
+use clap::Parser;
+use tracing::info_span;
+
+use crate::{input::InputArgs, setup_tracing, Answer, Progress, Solution};
+
+/// Arguments shared by all part binaries. Binaries with additional arguments
+/// can flatten them into their own parser and call `run_with`.
+#[derive(clap::Args)]
+pub struct CommonArgs {
+    #[command(flatten)]
+    input: InputArgs,
+    /// Part to solve, defaults to the part of the binary
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Print tracing output to stderr, filtered by RUST_LOG if it is set
+    #[arg(long)]
+    trace: bool,
+}
+
+#[derive(Parser)]
+struct Args {
+    #[command(flatten)]
+    common: CommonArgs,
+}
+
+/// Entry point of the part binaries: parse the command line arguments, read
+/// the input file, solve one part of `S` and print the answer. The parse and
+/// solve times go to stderr so that stdout only holds the answer. If the
+/// input can't be parsed or the part can't be solved, the error goes to
+/// stderr and the process exits with 1.
+pub fn run_main<S: Solution>(part: u8, input_path: &str) {
+    run_with::<S>(
+        Args::parse().common,
+        part,
+        input_path,
+        |part, parsed, progress| match part {
+            1 => S::part1_with_progress(parsed, progress),
+            _ => S::part2_with_progress(parsed, progress),
+        },
+    );
+}
+
+/// Like `run_main`, but with already parsed arguments and `solve` deciding how
+/// to solve the selected part.
+pub fn run_with<S: Solution>(
+    args: CommonArgs,
+    part: u8,
+    input_path: &str,
+    solve: impl FnOnce(u8, &S::Parsed<'_>, &dyn Progress) -> Answer,
+) {
+    if args.trace || env::var_os("RUST_LOG").is_some() {
+        setup_tracing();
+    }
+    let part = args.part.unwrap_or(part);
+    let input = args
+        .input
+        .source(input_path)
+        .read()
+        .unwrap_or_else(|e| exit_with(e));
+
+    let _day_span = info_span!("day", day = S::DAY).entered();
+    let start = Instant::now();
+    let parsed = info_span!("parse")
+        .in_scope(|| S::parse(&input))
+        .unwrap_or_else(|e| exit_with(e));
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    #[cfg(feature = "indicatif")]
+    let progress = crate::progress::ProgressBar::new();
+    #[cfg(not(feature = "indicatif"))]
+    let progress = crate::NoProgress;
+    let output = info_span!("part", part).in_scope(|| solve(part, &parsed, &progress));
+    #[cfg(feature = "indicatif")]
+    progress.finish();
+    let solve_time = start.elapsed();
+
+    println!("{}", output.unwrap_or_else(|e| exit_with(e)));
+    eprintln!("parse: {parse_time:?}, part {part}: {solve_time:?}");
+}
+
+fn exit_with(error: impl Display) -> ! {
+    eprintln!("{error}");
+    process::exit(1);
+}
+
+/// Generate the `main` function of a part binary.
+///
+/// ```ignore
+/// aoc_common::aoc_main!(aoc2023_day_05::Day05, part2);
+/// aoc_common::aoc_main!(aoc2023_day_01::Day01, part1, "input_part1.txt");
+/// ```
+#[macro_export]
+macro_rules! aoc_main {
+    ($solution:ty, part1) => {
+        $crate::aoc_main!($solution, part1, "input.txt");
+    };
+    ($solution:ty, part2) => {
+        $crate::aoc_main!($solution, part2, "input.txt");
+    };
+    ($solution:ty, part1, $input:expr) => {
+        fn main() {
+            $crate::entrypoint::run_main::<$solution>(1, $input);
+        }
+    };
+    ($solution:ty, part2, $input:expr) => {
+        fn main() {
+            $crate::entrypoint::run_main::<$solution>(2, $input);
+        }
+    };
+}
@@ -0,0 +1,226 @@
+use core::ops::{Add, RangeInclusive, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub fn new(x: i64, y: i64, z: i64) -> Point3 {
+        Point3 { x, y, z }
+    }
+
+    pub fn get(&self, axis: Axis) -> i64 {
+        match axis {
+            Axis::X => self.x,
+            Axis::Y => self.y,
+            Axis::Z => self.z,
+        }
+    }
+
+    pub fn manhattan_distance(&self, other: &Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+}
+
+impl Add for Point3 {
+    type Output = Point3;
+
+    fn add(self, other: Point3) -> Point3 {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Point3;
+
+    fn sub(self, other: Point3) -> Point3 {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+/// An axis-aligned box of cells, with both corners included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Aabb {
+    min: Point3,
+    max: Point3,
+}
+
+impl Aabb {
+    /// Create the box spanned by two arbitrary corners.
+    pub fn new(a: Point3, b: Point3) -> Aabb {
+        Aabb {
+            min: Point3::new(a.x.min(b.x), a.y.min(b.y), a.z.min(b.z)),
+            max: Point3::new(a.x.max(b.x), a.y.max(b.y), a.z.max(b.z)),
+        }
+    }
+
+    pub fn min(&self) -> Point3 {
+        self.min
+    }
+
+    pub fn max(&self) -> Point3 {
+        self.max
+    }
+
+    /// Range of coordinates the box covers along the given axis.
+    pub fn project(&self, axis: Axis) -> RangeInclusive<i64> {
+        self.min.get(axis)..=self.max.get(axis)
+    }
+
+    pub fn len(&self, axis: Axis) -> u64 {
+        (self.max.get(axis) - self.min.get(axis)) as u64 + 1
+    }
+
+    pub fn volume(&self) -> u64 {
+        self.len(Axis::X) * self.len(Axis::Y) * self.len(Axis::Z)
+    }
+
+    pub fn contains(&self, point: &Point3) -> bool {
+        [Axis::X, Axis::Y, Axis::Z]
+            .iter()
+            .all(|&axis| self.project(axis).contains(&point.get(axis)))
+    }
+
+    /// Check whether the projections of both boxes overlap on the given axis.
+    pub fn overlaps_on(&self, other: &Aabb, axis: Axis) -> bool {
+        self.min.get(axis) <= other.max.get(axis) && other.min.get(axis) <= self.max.get(axis)
+    }
+
+    pub fn overlaps(&self, other: &Aabb) -> bool {
+        [Axis::X, Axis::Y, Axis::Z]
+            .iter()
+            .all(|&axis| self.overlaps_on(other, axis))
+    }
+
+    pub fn intersection(&self, other: &Aabb) -> Option<Aabb> {
+        if !self.overlaps(other) {
+            return None;
+        }
+        Some(Aabb {
+            min: Point3::new(
+                self.min.x.max(other.min.x),
+                self.min.y.max(other.min.y),
+                self.min.z.max(other.min.z),
+            ),
+            max: Point3::new(
+                self.max.x.min(other.max.x),
+                self.max.y.min(other.max.y),
+                self.max.z.min(other.max.z),
+            ),
+        })
+    }
+
+    pub fn translate(&self, offset: Point3) -> Aabb {
+        Aabb {
+            min: self.min + offset,
+            max: self.max + offset,
+        }
+    }
+
+    /// Iterate over all cells inside the box, with x changing fastest.
+    pub fn cells(&self) -> impl Iterator<Item = Point3> + '_ {
+        self.project(Axis::Z).flat_map(move |z| {
+            self.project(Axis::Y)
+                .flat_map(move |y| self.project(Axis::X).map(move |x| Point3::new(x, y, z)))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_aabb_new_normalizes_corners() {
+        let aabb = Aabb::new(Point3::new(3, 0, 5), Point3::new(1, 2, 5));
+        assert_eq!(aabb.min(), Point3::new(1, 0, 5));
+        assert_eq!(aabb.max(), Point3::new(3, 2, 5));
+        assert_eq!(aabb.project(Axis::X), 1..=3);
+        assert_eq!(aabb.volume(), 9);
+    }
+
+    #[test]
+    fn test_aabb_cells() {
+        let aabb = Aabb::new(Point3::new(0, 0, 1), Point3::new(1, 0, 2));
+        assert_eq!(
+            aabb.cells().collect::<Vec<_>>(),
+            vec![
+                Point3::new(0, 0, 1),
+                Point3::new(1, 0, 1),
+                Point3::new(0, 0, 2),
+                Point3::new(1, 0, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_aabb_intersection() {
+        let a = Aabb::new(Point3::new(1, 0, 1), Point3::new(1, 2, 1));
+        let b = Aabb::new(Point3::new(0, 0, 2), Point3::new(2, 0, 2));
+        let c = Aabb::new(Point3::new(0, 2, 1), Point3::new(2, 2, 1));
+        assert!(a.overlaps_on(&b, Axis::X));
+        assert!(!a.overlaps(&b));
+        assert_eq!(a.intersection(&b), None);
+        assert_eq!(
+            a.intersection(&c),
+            Some(Aabb::new(Point3::new(1, 2, 1), Point3::new(1, 2, 1)))
+        );
+        assert_eq!(
+            a.translate(Point3::new(0, 0, 1))
+                .intersection(&b)
+                .map(|i| i.volume()),
+            Some(1)
+        );
+    }
+
+    fn point() -> impl Strategy<Value = Point3> {
+        (-8i64..8, -8i64..8, -8i64..8).prop_map(|(x, y, z)| Point3::new(x, y, z))
+    }
+
+    fn aabb() -> impl Strategy<Value = Aabb> {
+        (point(), point()).prop_map(|(a, b)| Aabb::new(a, b))
+    }
+
+    proptest! {
+        #[test]
+        fn prop_overlap_is_symmetric(a in aabb(), b in aabb()) {
+            prop_assert_eq!(a.overlaps(&b), b.overlaps(&a));
+            prop_assert_eq!(a.intersection(&b), b.intersection(&a));
+        }
+
+        #[test]
+        fn prop_intersection_matches_cells(a in aabb(), b in aabb()) {
+            let shared = a.cells().filter(|cell| b.contains(cell)).count() as u64;
+            match a.intersection(&b) {
+                Some(intersection) => {
+                    prop_assert_eq!(intersection.volume(), shared);
+                    prop_assert!(intersection.cells().all(|cell| a.contains(&cell) && b.contains(&cell)));
+                }
+                None => prop_assert_eq!(shared, 0),
+            }
+        }
+
+        #[test]
+        fn prop_cells_match_volume(a in aabb()) {
+            prop_assert_eq!(a.cells().count() as u64, a.volume());
+        }
+
+        #[test]
+        fn prop_translate_preserves_volume(a in aabb(), offset in point()) {
+            let moved = a.translate(offset);
+            prop_assert_eq!(moved.volume(), a.volume());
+            prop_assert_eq!(moved.min() - a.min(), offset);
+        }
+    }
+}
@@ -0,0 +1,250 @@
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    #[error("The grid has no cells")]
+    EmptyGridError,
+    #[error("Row {row} has {found} cells, expected {expected}")]
+    RaggedRowError {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+/// Offsets of the horizontal and vertical neighbors of a cell.
+const NEIGHBORS4: [(i32, i32); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+/// Offsets of the eight neighbors of a cell.
+const NEIGHBORS8: [(i32, i32); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, stored row by row. Positions are given as
+/// `(x, y)` with `x` the column and `y` the row, both starting at the top
+/// left. They are signed so that neighbors outside of the grid can be looked
+/// up without special casing the borders.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from its rows, which all need to have the same length.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Grid<T>, GridError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for (row, mut cells_of_row) in rows.into_iter().enumerate() {
+            let expected = *width.get_or_insert(cells_of_row.len());
+            if cells_of_row.len() != expected {
+                return Err(GridError::RaggedRowError {
+                    row,
+                    expected,
+                    found: cells_of_row.len(),
+                });
+            }
+            cells.append(&mut cells_of_row);
+            height += 1;
+        }
+        let width = width
+            .filter(|width| *width > 0)
+            .ok_or(GridError::EmptyGridError)?;
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Append a row at the bottom, it needs to have the width of the grid.
+    pub fn push_row(&mut self, mut row: Vec<T>) -> Result<(), GridError> {
+        if row.len() != self.width {
+            return Err(GridError::RaggedRowError {
+                row: self.height,
+                expected: self.width,
+                found: row.len(),
+            });
+        }
+        self.cells.append(&mut row);
+        self.height += 1;
+        Ok(())
+    }
+
+    /// Remove the top row, unless it is the only one.
+    pub fn pop_first_row(&mut self) {
+        if self.height > 1 {
+            self.cells.drain(..self.width);
+            self.height -= 1;
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, x: i32, y: i32) -> bool {
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    /// The cell at `(x, y)`, `None` if the position is outside of the grid.
+    pub fn get(&self, x: i32, y: i32) -> Option<&T> {
+        if self.contains(x, y) {
+            self.cells.get(y as usize * self.width + x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: i32, y: i32) -> Option<&mut T> {
+        if self.contains(x, y) {
+            self.cells.get_mut(y as usize * self.width + x as usize)
+        } else {
+            None
+        }
+    }
+
+    /// The row `y`, panics if it is outside of the grid.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl ExactSizeIterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// All `N` consecutive rows, starting at the top. There are no windows if
+    /// the grid has less than `N` rows.
+    pub fn row_windows<const N: usize>(&self) -> impl Iterator<Item = [&[T]; N]> {
+        (0..(self.height + 1).saturating_sub(N))
+            .map(move |y| core::array::from_fn(|offset| self.row(y + offset)))
+    }
+
+    /// All cells together with their position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.cells.iter().enumerate().map(|(idx, cell)| {
+            let position = ((idx % self.width) as i32, (idx / self.width) as i32);
+            (position, cell)
+        })
+    }
+
+    /// The horizontal and vertical neighbors of `(x, y)` that are inside of
+    /// the grid, together with their position.
+    pub fn neighbors4(&self, x: i32, y: i32) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.neighbors(x, y, &NEIGHBORS4)
+    }
+
+    /// The horizontal, vertical and diagonal neighbors of `(x, y)` that are
+    /// inside of the grid, together with their position.
+    pub fn neighbors8(&self, x: i32, y: i32) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.neighbors(x, y, &NEIGHBORS8)
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        x: i32,
+        y: i32,
+        offsets: &'static [(i32, i32)],
+    ) -> impl Iterator<Item = ((i32, i32), &'a T)> {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let (x, y) = (x + dx, y + dy);
+            self.get(x, y).map(|cell| ((x, y), cell))
+        })
+    }
+}
+
+/// Parse a grid with one character per cell and one row per line.
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::from_rows(s.lines().map(|line| line.chars().collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::*;
+
+    #[test]
+    fn test_grid() {
+        let grid: Grid<char> = "abc\ndef\n".parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(2, 1), Some(&'f'));
+        assert_eq!(grid.get(-1, 0), None);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&['a', 'b', 'c'], &['d', 'e', 'f']]
+        );
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &'e')));
+
+        let mut neighbors: Vec<char> = grid.neighbors8(0, 0).map(|(_, c)| *c).collect();
+        neighbors.sort();
+        assert_eq!(neighbors, vec!['b', 'd', 'e']);
+        assert_eq!(grid.neighbors8(1, 1).count(), 5);
+        assert_eq!(
+            grid.neighbors4(1, 1).collect::<Vec<_>>(),
+            vec![((1, 0), &'b'), ((0, 1), &'d'), ((2, 1), &'f')]
+        );
+    }
+
+    #[test]
+    fn test_row_windows() {
+        let grid: Grid<char> = "a\nb\nc\nd".parse().unwrap();
+        let windows: Vec<String> = grid
+            .row_windows::<3>()
+            .map(|rows| rows.iter().map(|row| row[0]).collect())
+            .collect();
+        assert_eq!(windows, vec!["abc", "bcd"]);
+        assert_eq!(grid.row_windows::<5>().count(), 0);
+    }
+
+    #[test]
+    fn test_push_and_pop_rows() {
+        let mut grid: Grid<char> = "ab".parse().unwrap();
+        grid.push_row(vec!['c', 'd']).unwrap();
+        assert_eq!(grid.height(), 2);
+        assert_eq!(
+            grid.push_row(vec!['e']),
+            Err(GridError::RaggedRowError {
+                row: 2,
+                expected: 2,
+                found: 1
+            })
+        );
+        grid.pop_first_row();
+        assert_eq!(grid.rows().collect::<Vec<_>>(), vec![&['c', 'd']]);
+        grid.pop_first_row();
+        assert_eq!(grid.height(), 1);
+    }
+
+    #[test]
+    fn test_grid_errors() {
+        assert_eq!("".parse::<Grid<char>>(), Err(GridError::EmptyGridError));
+        assert_eq!(
+            "ab\nabc".parse::<Grid<char>>(),
+            Err(GridError::RaggedRowError {
+                row: 1,
+                expected: 2,
+                found: 3
+            })
+        );
+    }
+}
@@ -0,0 +1,166 @@
+use std::{
+    fmt::Display,
+    fs::{self, File},
+    io::{self, Read},
+    ops::Deref,
+    path::{Path, PathBuf},
+    str,
+};
+
+use memmap2::Mmap;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum InputError {
+    #[error("Could not read {0}: {1}")]
+    ReadError(InputSource, io::Error),
+    #[error("{0} is not valid UTF-8: {1}")]
+    InvalidUtf8Error(InputSource, str::Utf8Error),
+}
+
+/// Where the puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// Read the whole file into memory.
+    File(PathBuf),
+    Stdin,
+    /// Map the file into memory, so that large inputs are not copied.
+    Mapped(PathBuf),
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::File(path) | InputSource::Mapped(path) => {
+                write!(f, "`{}`", path.display())
+            }
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+/// The contents of an input, which derefs to the text.
+pub struct Input(Contents);
+
+enum Contents {
+    Owned(String),
+    /// Checked to be valid UTF-8 when mapping.
+    Mapped(Mmap),
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        match &self.0 {
+            Contents::Owned(text) => text,
+            // SAFETY: the bytes were checked by `InputSource::read`
+            Contents::Mapped(map) => unsafe { str::from_utf8_unchecked(map) },
+        }
+    }
+}
+
+impl InputSource {
+    /// The source of a command line argument, `-` is stdin.
+    pub fn new(path: impl Into<PathBuf>, mmap: bool) -> InputSource {
+        let path = path.into();
+        match path.to_str() {
+            Some("-") => InputSource::Stdin,
+            _ if mmap => InputSource::Mapped(path),
+            _ => InputSource::File(path),
+        }
+    }
+
+    pub fn read(&self) -> Result<Input, InputError> {
+        let read_error = |e| InputError::ReadError(self.clone(), e);
+        let contents = match self {
+            InputSource::File(path) => {
+                Contents::Owned(fs::read_to_string(path).map_err(read_error)?)
+            }
+            InputSource::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text).map_err(read_error)?;
+                Contents::Owned(text)
+            }
+            InputSource::Mapped(path) => {
+                let map = map_file(path).map_err(read_error)?;
+                str::from_utf8(&map).map_err(|e| InputError::InvalidUtf8Error(self.clone(), e))?;
+                Contents::Mapped(map)
+            }
+        };
+        Ok(Input(contents))
+    }
+}
+
+fn map_file(path: &Path) -> io::Result<Mmap> {
+    let file = File::open(path)?;
+    // SAFETY: the file must not be modified while it is mapped, which can't
+    // be prevented for files that other processes can write to. The inputs
+    // are not written to while solving.
+    unsafe { Mmap::map(&file) }
+}
+
+/// Command line arguments to select the input of a binary.
+#[derive(clap::Args, Debug)]
+pub struct InputArgs {
+    /// Input file, `-` reads from stdin
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Map the input file into memory instead of reading it, for large inputs
+    #[arg(long)]
+    mmap: bool,
+}
+
+impl InputArgs {
+    /// The selected input, or the file `default`.
+    pub fn source(&self, default: &str) -> InputSource {
+        let path = self.input.clone().unwrap_or_else(|| default.into());
+        InputSource::new(path, self.mmap)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::*;
+    use std::env;
+
+    #[test]
+    fn test_read() {
+        let path = env::temp_dir().join("aoc-common-test-input.txt");
+        fs::write(&path, "467..114..\n...*......\n").unwrap();
+        for mmap in [false, true] {
+            let input = InputSource::new(&path, mmap).read().unwrap();
+            assert_eq!(&*input, "467..114..\n...*......\n");
+        }
+
+        fs::write(&path, "").unwrap();
+        assert_eq!(&*InputSource::Mapped(path.clone()).read().unwrap(), "");
+
+        fs::write(&path, b"\xff").unwrap();
+        assert!(matches!(
+            InputSource::Mapped(path.clone()).read(),
+            Err(InputError::InvalidUtf8Error(..))
+        ));
+        assert!(matches!(
+            InputSource::File(path).read(),
+            Err(InputError::ReadError(..))
+        ));
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(InputSource::new("-", true), InputSource::Stdin);
+        assert_eq!(
+            InputSource::new("input.txt", true),
+            InputSource::Mapped("input.txt".into())
+        );
+        assert_eq!(
+            InputSource::new("input.txt", false),
+            InputSource::File("input.txt".into())
+        );
+        assert_eq!(
+            InputSource::File("missing.txt".into()).to_string(),
+            "`missing.txt`"
+        );
+    }
+}
@@ -0,0 +1,235 @@
+use alloc::vec::Vec;
+use core::{
+    cmp::{max, min},
+    ops::{Add, Range, Sub},
+};
+
+/// A set of values stored as sorted, half-open intervals. Overlapping and
+/// adjacent intervals are merged, so every set has exactly one
+/// representation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Range<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: Ord + Copy> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet::default()
+    }
+
+    /// Add all values of `interval`, empty intervals are ignored.
+    pub fn insert(&mut self, interval: Range<T>) {
+        if interval.is_empty() {
+            return;
+        }
+        // intervals before `first` end before the new one starts, intervals
+        // from `last` on start after it ends, everything in between is merged
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        let mut merged = interval;
+        if first < last {
+            merged.start = min(merged.start, self.intervals[first].start);
+            merged.end = max(merged.end, self.intervals[last - 1].end);
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn intervals(&self) -> &[Range<T>] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &Range<T>> {
+        self.intervals.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of intervals, not of values.
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let position = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals
+            .get(position)
+            .is_some_and(|i| i.start <= value)
+    }
+
+    /// The smallest value of the set.
+    pub fn first(&self) -> Option<T> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        union.extend(other.iter().cloned());
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::new();
+        let (mut a, mut b) = (0, 0);
+        while let (Some(i), Some(j)) = (self.intervals.get(a), other.intervals.get(b)) {
+            let start = max(i.start, j.start);
+            let end = min(i.end, j.end);
+            if start < end {
+                intervals.push(start..end);
+            }
+            if i.end < j.end {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The values of this set that are not in `other`.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::new();
+        let mut b = 0;
+        for i in &self.intervals {
+            while other.intervals.get(b).is_some_and(|j| j.end <= i.start) {
+                b += 1;
+            }
+            let mut start = i.start;
+            for j in other.intervals[b..].iter().take_while(|j| j.start < i.end) {
+                if start < j.start {
+                    intervals.push(start..j.start);
+                }
+                start = max(start, j.end);
+            }
+            if start < i.end {
+                intervals.push(start..i.end);
+            }
+        }
+        IntervalSet { intervals }
+    }
+}
+
+impl<T: Ord + Copy + Add<Output = T> + Sub<Output = T>> IntervalSet<T> {
+    /// Move all values so that `from` ends up at `to`.
+    pub fn translate(&self, from: T, to: T) -> IntervalSet<T> {
+        let shift = |value: T| {
+            if to >= from {
+                value + (to - from)
+            } else {
+                value - (from - to)
+            }
+        };
+        IntervalSet {
+            intervals: self
+                .intervals
+                .iter()
+                .map(|i| shift(i.start)..shift(i.end))
+                .collect(),
+        }
+    }
+}
+
+impl<T: Ord + Copy> From<Range<T>> for IntervalSet<T> {
+    fn from(interval: Range<T>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(interval);
+        set
+    }
+}
+
+impl<T: Ord + Copy> Extend<Range<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, intervals: I) {
+        for interval in intervals {
+            self.insert(interval);
+        }
+    }
+}
+
+impl<T: Ord + Copy> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(intervals: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(intervals);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::interval::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_insert_merges() {
+        let mut set = IntervalSet::new();
+        set.insert(10..12);
+        set.insert(0..3);
+        set.insert(5..5);
+        assert_eq!(set.intervals(), &[0..3, 10..12]);
+        set.insert(3..4);
+        set.insert(8..10);
+        assert_eq!(set.intervals(), &[0..4, 8..12]);
+        set.insert(2..9);
+        assert_eq!(set, IntervalSet::from(0..12));
+        assert!(set.contains(11));
+        assert!(!set.contains(12));
+        assert_eq!(set.first(), Some(0));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a: IntervalSet<usize> = [0..5, 10..20].into_iter().collect();
+        let b: IntervalSet<usize> = [3..12, 15..16, 19..30].into_iter().collect();
+        assert_eq!(a.union(&b), IntervalSet::from(0..30));
+        assert_eq!(
+            a.intersection(&b).intervals(),
+            &[3..5, 10..12, 15..16, 19..20]
+        );
+        assert_eq!(a.difference(&b).intervals(), &[0..3, 12..15, 16..19]);
+        assert_eq!(b.difference(&a).intervals(), &[5..10, 20..30]);
+        assert_eq!(a.translate(0, 5).intervals(), &[5..10, 15..25]);
+        assert_eq!(b.translate(3, 0).intervals(), &[0..9, 12..13, 16..27]);
+    }
+
+    fn interval_set() -> impl Strategy<Value = IntervalSet<i32>> {
+        prop::collection::vec((0..40, 0..8), 0..6)
+            .prop_map(|intervals| intervals.into_iter().map(|(s, l)| s..s + l).collect())
+    }
+
+    fn values(set: &IntervalSet<i32>) -> Vec<i32> {
+        set.iter().flat_map(|i| i.clone()).collect()
+    }
+
+    proptest! {
+        #[test]
+        fn prop_intervals_are_normalized(set in interval_set()) {
+            for pair in set.intervals().windows(2) {
+                prop_assert!(pair[0].end < pair[1].start);
+            }
+            prop_assert!(set.iter().all(|i| !i.is_empty()));
+        }
+
+        #[test]
+        fn prop_operations_match_values(a in interval_set(), b in interval_set()) {
+            for value in -1..50 {
+                prop_assert_eq!(a.union(&b).contains(value), a.contains(value) || b.contains(value));
+                prop_assert_eq!(a.intersection(&b).contains(value), a.contains(value) && b.contains(value));
+                prop_assert_eq!(a.difference(&b).contains(value), a.contains(value) && !b.contains(value));
+            }
+        }
+
+        #[test]
+        fn prop_translate_moves_values(set in interval_set(), from in 0..40, to in 0..40) {
+            let moved: Vec<i32> = values(&set).into_iter().map(|v| v - from + to).collect();
+            prop_assert_eq!(values(&set.translate(from, to)), moved);
+        }
+    }
+}
@@ -0,0 +1,27 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod collections;
+#[cfg(feature = "std")]
+pub mod entrypoint;
+pub mod geometry;
+pub mod grid;
+#[cfg(feature = "std")]
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod progress;
+#[cfg(feature = "std")]
+pub mod registry;
+pub mod solution;
+pub mod text;
+#[cfg(feature = "std")]
+pub mod trace;
+
+#[cfg(feature = "std")]
+pub use input::{Input, InputSource};
+pub use progress::{NoProgress, Progress};
+pub use solution::{Answer, MergeError, Solution, SolveError};
+#[cfg(feature = "std")]
+pub use trace::setup_tracing;
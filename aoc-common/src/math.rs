@@ -0,0 +1,40 @@
+/// The greatest common divisor of `a` and `b`, zero if both are zero.
+pub fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The least common multiple of `a` and `b`, `None` if it overflows. It is
+/// zero if one of them is zero.
+pub fn checked_lcm(a: usize, b: usize) -> Option<usize> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::*;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(18, 12), 6);
+        assert_eq!(gcd(7, 13), 1);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(0, 0), 0);
+    }
+
+    #[test]
+    fn test_checked_lcm() {
+        assert_eq!(checked_lcm(4, 6), Some(12));
+        assert_eq!(checked_lcm(1, 9), Some(9));
+        assert_eq!(checked_lcm(0, 9), Some(0));
+        assert_eq!(checked_lcm(usize::MAX, usize::MAX), Some(usize::MAX));
+        assert_eq!(checked_lcm(usize::MAX, 2), None);
+    }
+}
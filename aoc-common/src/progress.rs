@@ -0,0 +1,47 @@
+/// Receiver for progress updates of long-running parts.
+pub trait Progress: Sync {
+    /// Announce how many steps the part is going to take.
+    fn set_total(&self, total: u64);
+    /// Mark `delta` more steps as done.
+    fn advance(&self, delta: u64);
+}
+
+/// Ignores all progress updates.
+pub struct NoProgress;
+
+impl Progress for NoProgress {
+    fn set_total(&self, _total: u64) {}
+    fn advance(&self, _delta: u64) {}
+}
+
+#[cfg(feature = "indicatif")]
+pub struct ProgressBar(indicatif::ProgressBar);
+
+#[cfg(feature = "indicatif")]
+impl ProgressBar {
+    pub fn new() -> ProgressBar {
+        ProgressBar(indicatif::ProgressBar::new(0))
+    }
+
+    pub fn finish(&self) {
+        self.0.finish_and_clear()
+    }
+}
+
+#[cfg(feature = "indicatif")]
+impl Default for ProgressBar {
+    fn default() -> Self {
+        ProgressBar::new()
+    }
+}
+
+#[cfg(feature = "indicatif")]
+impl Progress for ProgressBar {
+    fn set_total(&self, total: u64) {
+        self.0.set_length(total)
+    }
+
+    fn advance(&self, delta: u64) {
+        self.0.inc(delta)
+    }
+}
@@ -0,0 +1,121 @@
+//! The days register their `Solution` with `register_day!`, so that the
+//! runners find every day that is linked into them without listing the days.
+
+use crate::{Answer, Progress, Solution, SolveError};
+
+#[doc(hidden)]
+pub use inventory;
+
+/// The parsed input of a registered day, with the type of the day erased.
+pub trait ParsedInput {
+    fn part1(&self) -> Answer;
+    fn part2(&self) -> Answer;
+    fn part1_with_progress(&self, progress: &dyn Progress) -> Answer;
+    fn part2_with_progress(&self, progress: &dyn Progress) -> Answer;
+}
+
+/// A parsed input of any registered day.
+pub type BoxedInput<'a> = Box<dyn ParsedInput + 'a>;
+
+struct Parsed<'a, S: Solution>(S::Parsed<'a>);
+
+impl<S: Solution> ParsedInput for Parsed<'_, S> {
+    fn part1(&self) -> Answer {
+        S::part1(&self.0)
+    }
+
+    fn part2(&self) -> Answer {
+        S::part2(&self.0)
+    }
+
+    fn part1_with_progress(&self, progress: &dyn Progress) -> Answer {
+        S::part1_with_progress(&self.0, progress)
+    }
+
+    fn part2_with_progress(&self, progress: &dyn Progress) -> Answer {
+        S::part2_with_progress(&self.0, progress)
+    }
+}
+
+fn parse<'a, S: Solution + 'static>(input: &'a str) -> Result<BoxedInput<'a>, SolveError> {
+    Ok(Box::new(Parsed::<S>(S::parse(input)?)))
+}
+
+fn parse_merged<'a, S: Solution + 'static>(
+    inputs: &[&'a str],
+) -> Result<BoxedInput<'a>, SolveError> {
+    let mut parsed = inputs
+        .iter()
+        .map(|input| S::parse(input))
+        .collect::<Result<Vec<_>, _>>()?;
+    let parsed = match parsed.len() {
+        1 => parsed.remove(0),
+        _ => S::merge(parsed)?,
+    };
+    Ok(Box::new(Parsed::<S>(parsed)))
+}
+
+/// The `Solution` of a day, as registered by `register_day!`.
+pub struct RegisteredDay {
+    pub year: u16,
+    pub day: u8,
+    parse: for<'a> fn(&'a str) -> Result<BoxedInput<'a>, SolveError>,
+    parse_merged: for<'a> fn(&[&'a str]) -> Result<BoxedInput<'a>, SolveError>,
+    solve_both: fn(&str) -> Result<(String, String), SolveError>,
+}
+
+impl RegisteredDay {
+    pub const fn new<S: Solution + 'static>() -> RegisteredDay {
+        RegisteredDay {
+            year: S::YEAR,
+            day: S::DAY,
+            parse: parse::<S>,
+            parse_merged: parse_merged::<S>,
+            solve_both: S::solve_both,
+        }
+    }
+
+    pub fn parse<'a>(&self, input: &'a str) -> Result<BoxedInput<'a>, SolveError> {
+        (self.parse)(input)
+    }
+
+    /// Parse the inputs and merge them into a single dataset, a single input
+    /// is not merged. The error is a `MergeError` if the inputs were parsed
+    /// but could not be merged.
+    pub fn parse_merged<'a>(&self, inputs: &[&'a str]) -> Result<BoxedInput<'a>, SolveError> {
+        (self.parse_merged)(inputs)
+    }
+
+    pub fn solve_both(&self, input: &str) -> Result<(String, String), SolveError> {
+        (self.solve_both)(input)
+    }
+}
+
+inventory::collect!(RegisteredDay);
+
+/// All registered days, ordered by year and day.
+pub fn days() -> Vec<&'static RegisteredDay> {
+    let mut days: Vec<_> = inventory::iter::<RegisteredDay>().collect();
+    days.sort_by_key(|registered| (registered.year, registered.day));
+    days
+}
+
+/// The registered days of `year`, ordered by day.
+pub fn days_of(year: u16) -> Vec<&'static RegisteredDay> {
+    let mut days = days();
+    days.retain(|registered| registered.year == year);
+    days
+}
+
+/// The years with registered days, in order.
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = days().iter().map(|registered| registered.year).collect();
+    years.dedup();
+    years
+}
+
+/// The registered `day` of `year`, if it is linked in.
+pub fn day(year: u16, day: u8) -> Option<&'static RegisteredDay> {
+    inventory::iter::<RegisteredDay>()
+        .find(|registered| registered.year == year && registered.day == day)
+}
@@ -0,0 +1,81 @@
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::error::Error;
+
+use thiserror::Error;
+
+use crate::Progress;
+
+#[derive(Error, Debug)]
+pub enum MergeError {
+    #[error("Merging inputs is not supported")]
+    Unsupported,
+    #[error("Inputs are incompatible: {0}")]
+    Incompatible(String),
+}
+
+/// Why a day could not parse its input or solve a part of it.
+pub type SolveError = Box<dyn Error + Send + Sync>;
+
+/// The answer to a part, or why it could not be computed.
+pub type Answer = Result<String, SolveError>;
+
+/// Common interface of all days, so that they can be driven by generic tooling.
+pub trait Solution {
+    /// Year of the puzzle, days of different years are told apart by it.
+    const YEAR: u16;
+    /// Day of the puzzle, used to label output and tracing spans.
+    const DAY: u8;
+
+    /// Parsed puzzle input, which may borrow from the raw input.
+    type Parsed<'a>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError>;
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer;
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer;
+
+    /// Solve part 1 while reporting progress. Long-running days override
+    /// this, all others ignore `progress`.
+    fn part1_with_progress(parsed: &Self::Parsed<'_>, progress: &dyn Progress) -> Answer {
+        let _ = progress;
+        Self::part1(parsed)
+    }
+
+    /// Solve part 2 while reporting progress. Long-running days override
+    /// this, all others ignore `progress`.
+    fn part2_with_progress(parsed: &Self::Parsed<'_>, progress: &dyn Progress) -> Answer {
+        let _ = progress;
+        Self::part2(parsed)
+    }
+
+    /// Solve both parts at once. Days that can share work between the parts
+    /// override this.
+    fn solve_both(input: &str) -> Result<(String, String), SolveError> {
+        let parsed = Self::parse(input)?;
+        Ok((Self::part1(&parsed)?, Self::part2(&parsed)?))
+    }
+
+    /// Merge several parsed inputs into a single dataset.
+    fn merge(parsed: Vec<Self::Parsed<'_>>) -> Result<Self::Parsed<'_>, MergeError> {
+        let _ = parsed;
+        Err(MergeError::Unsupported)
+    }
+}
+
+/// Register the `Solution` of a day, for the runners that find the days with
+/// `registry::days`. Without `std` nothing is registered.
+///
+/// The registration is only linked in if something of its object file is
+/// used, so `aoc-days` refers to the `REGISTRATION` static next to it.
+#[macro_export]
+macro_rules! register_day {
+    ($solution:ty) => {
+        #[cfg(feature = "std")]
+        $crate::registry::inventory::submit! {
+            $crate::registry::RegisteredDay::new::<$solution>()
+        }
+
+        #[cfg(feature = "std")]
+        #[doc(hidden)]
+        pub static REGISTRATION: u8 = 0;
+    };
+}
@@ -0,0 +1,23 @@
+/// The 1-based line and column of the byte `offset` in `input`, for error
+/// messages. The column counts characters, not bytes.
+pub fn line_column(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |position| position + 1);
+    let line = before.matches('\n').count() + 1;
+    (line, before[line_start..].chars().count() + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::text::*;
+
+    #[test]
+    fn test_line_column() {
+        let input = "seeds: 1\n\nrød-to-x map:\n1 2";
+        assert_eq!(line_column(input, 0), (1, 1));
+        assert_eq!(line_column(input, 8), (1, 9));
+        assert_eq!(line_column(input, 9), (2, 1));
+        assert_eq!(line_column(input, 14), (3, 4));
+        assert_eq!(line_column(input, input.len()), (4, 4));
+    }
+}
@@ -0,0 +1,13 @@
+use std::io;
+
+use tracing_subscriber::EnvFilter;
+
+/// Log to stderr, filtered by `RUST_LOG` if it is set and at debug level
+/// otherwise. Does nothing if a subscriber is already installed.
+pub fn setup_tracing() {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("debug"));
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .try_init();
+}
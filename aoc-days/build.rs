@@ -0,0 +1,25 @@
+//! Refer to every day that is a dependency, so that its registration is not
+//! dropped by the linker.
+
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    let manifest = fs::read_to_string("Cargo.toml").expect("Could not read Cargo.toml");
+    let days: Vec<&str> = manifest
+        .lines()
+        .filter_map(|line| line.split_once('=').map(|(name, _)| name.trim()))
+        // like `aoc2023_day_05`
+        .filter(|name| name.contains("_day_"))
+        .collect();
+    let registrations: String = days
+        .iter()
+        .map(|name| format!("    &{name}::REGISTRATION,\n"))
+        .collect();
+    let source = format!(
+        "static REGISTRATIONS: [&u8; {}] = [\n{registrations}];\n",
+        days.len()
+    );
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("days.rs"), source).expect("Could not write days.rs");
+}
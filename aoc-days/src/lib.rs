@@ -0,0 +1,84 @@
+//! All days of all years, for the runners that find them with `days` and
+//! `day`. A day is added by adding its crate to the dependencies, which
+//! `cargo xtask new-day` does.
+
+use std::hint::black_box;
+
+use aoc_common::registry;
+pub use aoc_common::registry::{ParsedInput, RegisteredDay};
+
+// the `REGISTRATION` static of every day
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+/// Keep the registrations of the days in the binary. A static library or a
+/// wasm module only links the object files of a day that it refers to, and
+/// nothing else refers to the registration.
+fn link() {
+    black_box(&REGISTRATIONS);
+}
+
+/// All days, ordered by year and day.
+pub fn days() -> Vec<&'static RegisteredDay> {
+    link();
+    registry::days()
+}
+
+/// The days of `year`, ordered by day.
+pub fn days_of(year: u16) -> Vec<&'static RegisteredDay> {
+    link();
+    registry::days_of(year)
+}
+
+/// The years with days, in order.
+pub fn years() -> Vec<u16> {
+    link();
+    registry::years()
+}
+
+/// The `day` of `year`, if it is implemented.
+pub fn day(year: u16, day: u8) -> Option<&'static RegisteredDay> {
+    link();
+    registry::day(year, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn test_days() {
+        let registered: Vec<(u16, u8)> = days()
+            .iter()
+            .map(|registered| (registered.year, registered.day))
+            .collect();
+        assert!(registered.windows(2).all(|w| w[0] < w[1]));
+        let days_2023: Vec<u8> = days_of(2023)
+            .iter()
+            .map(|registered| registered.day)
+            .collect();
+        for day in (1..=21).chain([24]) {
+            assert!(days_2023.contains(&day), "day {day}");
+        }
+        assert!(years().contains(&2023));
+        assert!(years().windows(2).all(|w| w[0] < w[1]));
+        assert!(day(2023, 26).is_none());
+        assert!(day(2022, 4).is_none());
+
+        let input =
+            fs::read_to_string("../2023/day_04/input_test.txt").expect("Could not read the file");
+        let day_04 = day(2023, 4).unwrap();
+        let parsed = day_04.parse(&input).unwrap();
+        assert_eq!(parsed.part1().unwrap(), "13");
+        assert_eq!(parsed.part2().unwrap(), "30");
+        let merged = day_04.parse_merged(&[&input, &input]).unwrap();
+        assert_eq!(merged.part1().unwrap(), "26");
+        let (part1, part2) = day_04.solve_both(&input).unwrap();
+        assert_eq!((part1.as_str(), part2.as_str()), ("13", "30"));
+        assert!(day_04.parse("Card 1: x | 1").is_err());
+        assert!(day(2023, 3)
+            .unwrap()
+            .parse_merged(&[&input, &input])
+            .is_err());
+    }
+}
@@ -0,0 +1,89 @@
+#ifndef AOC_H
+#define AOC_H
+
+/* Generated with cbindgen from aoc-ffi/src/lib.rs, do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Outcome of `aoc_solve`.
+ */
+typedef enum AocStatus {
+  /**
+   * The answer was written to the output buffer.
+   */
+  AOC_STATUS_OK = 0,
+  AOC_STATUS_UNKNOWN_DAY = 1,
+  AOC_STATUS_UNKNOWN_PART = 2,
+  /**
+   * A required pointer was null.
+   */
+  AOC_STATUS_NULL_POINTER = 3,
+  /**
+   * The input is not valid UTF-8.
+   */
+  AOC_STATUS_INVALID_UTF8 = 4,
+  /**
+   * The output buffer can't hold the answer and its terminating NUL.
+   */
+  AOC_STATUS_BUFFER_TOO_SMALL = 5,
+  /**
+   * The solver panicked.
+   */
+  AOC_STATUS_PANIC = 6,
+  /**
+   * The input could not be parsed or the part could not be solved.
+   */
+  AOC_STATUS_INVALID_INPUT = 7,
+} AocStatus;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * The number of the last implemented day. Not every day before it is
+ * implemented, `aoc_solve` reports the missing ones as
+ * `AOC_STATUS_UNKNOWN_DAY`.
+ */
+uint8_t aoc_last_day(void);
+
+/**
+ * A static, NUL-terminated description of `status`, an `AocStatus`. The
+ * parameter is an integer because a value outside of the enum would be
+ * undefined behavior, such values get a message as well.
+ */
+const char *aoc_status_message(uint32_t status);
+
+/**
+ * Solve `part` of `day` for the `input_len` bytes of UTF-8 at `input`.
+ *
+ * The answer is written to `out_buf` as a NUL-terminated string, and its
+ * length without the NUL to `answer_len` unless that is null. If the
+ * `out_len` bytes of the buffer are too small, only `answer_len` is written,
+ * so the call can be repeated with a larger buffer. An input that can't be
+ * parsed or solved is reported as `AOC_STATUS_INVALID_INPUT`. Panics of the
+ * solvers are caught and reported as `AOC_STATUS_PANIC`.
+ *
+ * # Safety
+ *
+ * `input` must point to `input_len` readable bytes and `out_buf` to
+ * `out_len` writable bytes, `answer_len` must be null or writable.
+ */
+enum AocStatus aoc_solve(uint8_t day,
+                         uint8_t part,
+                         const uint8_t *input,
+                         size_t input_len,
+                         char *out_buf,
+                         size_t out_len,
+                         size_t *answer_len);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* AOC_H */
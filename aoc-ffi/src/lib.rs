@@ -0,0 +1,232 @@
+//! C interface of the solutions, to embed them in other programs. The
+//! declarations are in `include/aoc.h`, which is generated with cbindgen
+//! from this file. Link against `libaoc_ffi.a` or `libaoc_ffi.so`.
+
+use std::{
+    ffi::{c_char, CStr},
+    panic::{self, AssertUnwindSafe},
+    ptr, slice, str,
+};
+
+/// The year of the days that the interface solves.
+const YEAR: u16 = 2023;
+
+/// Outcome of `aoc_solve`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AocStatus {
+    /// The answer was written to the output buffer.
+    Ok = 0,
+    UnknownDay = 1,
+    UnknownPart = 2,
+    /// A required pointer was null.
+    NullPointer = 3,
+    /// The input is not valid UTF-8.
+    InvalidUtf8 = 4,
+    /// The output buffer can't hold the answer and its terminating NUL.
+    BufferTooSmall = 5,
+    /// The solver panicked.
+    Panic = 6,
+    /// The input could not be parsed or the part could not be solved.
+    InvalidInput = 7,
+}
+
+impl AocStatus {
+    /// The status with the value `status`, if there is one.
+    fn from_u32(status: u32) -> Option<AocStatus> {
+        Some(match status {
+            0 => AocStatus::Ok,
+            1 => AocStatus::UnknownDay,
+            2 => AocStatus::UnknownPart,
+            3 => AocStatus::NullPointer,
+            4 => AocStatus::InvalidUtf8,
+            5 => AocStatus::BufferTooSmall,
+            6 => AocStatus::Panic,
+            7 => AocStatus::InvalidInput,
+            _ => return None,
+        })
+    }
+
+    fn message(self) -> &'static CStr {
+        match self {
+            AocStatus::Ok => c"ok",
+            AocStatus::UnknownDay => c"The day is not implemented",
+            AocStatus::UnknownPart => c"The part does not exist",
+            AocStatus::NullPointer => c"A required pointer is null",
+            AocStatus::InvalidUtf8 => c"The input is not valid UTF-8",
+            AocStatus::BufferTooSmall => c"The output buffer is too small for the answer",
+            AocStatus::Panic => c"The solver panicked",
+            AocStatus::InvalidInput => {
+                c"The input could not be parsed or the part could not be solved"
+            }
+        }
+    }
+}
+
+fn solve_part(day: u8, part: u8, input: &str) -> Result<String, AocStatus> {
+    let registered = aoc_days::day(YEAR, day).ok_or(AocStatus::UnknownDay)?;
+    if !(1..=2).contains(&part) {
+        return Err(AocStatus::UnknownPart);
+    }
+    let parsed = registered
+        .parse(input)
+        .map_err(|_| AocStatus::InvalidInput)?;
+    let answer = match part {
+        1 => parsed.part1(),
+        _ => parsed.part2(),
+    };
+    answer.map_err(|_| AocStatus::InvalidInput)
+}
+
+/// Run `solve`, a panic must not unwind into the calling C code.
+fn catch_panic(solve: impl FnOnce() -> Result<String, AocStatus>) -> Result<String, AocStatus> {
+    panic::catch_unwind(AssertUnwindSafe(solve)).unwrap_or(Err(AocStatus::Panic))
+}
+
+/// The number of the last implemented day. Not every day before it is
+/// implemented, `aoc_solve` reports the missing ones as
+/// `AOC_STATUS_UNKNOWN_DAY`.
+#[no_mangle]
+pub extern "C" fn aoc_last_day() -> u8 {
+    aoc_days::days_of(YEAR)
+        .last()
+        .map_or(0, |registered| registered.day)
+}
+
+/// A static, NUL-terminated description of `status`, an `AocStatus`. The
+/// parameter is an integer because a value outside of the enum would be
+/// undefined behavior, such values get a message as well.
+#[no_mangle]
+pub extern "C" fn aoc_status_message(status: u32) -> *const c_char {
+    AocStatus::from_u32(status)
+        .map_or(c"Unknown status", AocStatus::message)
+        .as_ptr()
+}
+
+/// Solve `part` of `day` for the `input_len` bytes of UTF-8 at `input`.
+///
+/// The answer is written to `out_buf` as a NUL-terminated string, and its
+/// length without the NUL to `answer_len` unless that is null. If the
+/// `out_len` bytes of the buffer are too small, only `answer_len` is written,
+/// so the call can be repeated with a larger buffer. An input that can't be
+/// parsed or solved is reported as `AOC_STATUS_INVALID_INPUT`. Panics of the
+/// solvers are caught and reported as `AOC_STATUS_PANIC`.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes and `out_buf` to
+/// `out_len` writable bytes, `answer_len` must be null or writable.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    input_len: usize,
+    out_buf: *mut c_char,
+    out_len: usize,
+    answer_len: *mut usize,
+) -> AocStatus {
+    if (input.is_null() && input_len > 0) || out_buf.is_null() {
+        return AocStatus::NullPointer;
+    }
+    let input = match input_len {
+        0 => &[],
+        _ => slice::from_raw_parts(input, input_len),
+    };
+    let Ok(input) = str::from_utf8(input) else {
+        return AocStatus::InvalidUtf8;
+    };
+    let answer = match catch_panic(|| solve_part(day, part, input)) {
+        Ok(answer) => answer,
+        Err(status) => return status,
+    };
+
+    if !answer_len.is_null() {
+        *answer_len = answer.len();
+    }
+    if answer.len() >= out_len {
+        return AocStatus::BufferTooSmall;
+    }
+    ptr::copy_nonoverlapping(answer.as_ptr(), out_buf.cast(), answer.len());
+    *out_buf.add(answer.len()) = 0;
+    AocStatus::Ok
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    /// Call `aoc_solve` with a buffer of `out_len` bytes.
+    fn solve(day: u8, part: u8, input: &[u8], out_len: usize) -> (AocStatus, usize, String) {
+        let mut out_buf = vec![b'x' as c_char; out_len.max(1)];
+        let mut answer_len = 0;
+        let status = unsafe {
+            aoc_solve(
+                day,
+                part,
+                input.as_ptr(),
+                input.len(),
+                out_buf.as_mut_ptr(),
+                out_len,
+                &mut answer_len,
+            )
+        };
+        let answer = match status {
+            AocStatus::Ok => unsafe { CStr::from_ptr(out_buf.as_ptr()) }
+                .to_string_lossy()
+                .into_owned(),
+            _ => String::new(),
+        };
+        (status, answer_len, answer)
+    }
+
+    #[test]
+    fn test_aoc_solve() {
+        let input = fs::read("../2023/day_09/input_test.txt").expect("Could not read the file");
+        assert_eq!(
+            solve(9, 1, &input, 16),
+            (AocStatus::Ok, 3, "114".to_string())
+        );
+        assert_eq!(solve(9, 2, &input, 2), (AocStatus::Ok, 1, "2".to_string()));
+        // no space for the NUL
+        assert_eq!(
+            solve(9, 1, &input, 3),
+            (AocStatus::BufferTooSmall, 3, String::new())
+        );
+        assert_eq!(solve(9, 3, &input, 16).0, AocStatus::UnknownPart);
+        assert_eq!(solve(26, 1, &input, 16).0, AocStatus::UnknownDay);
+        assert_eq!(solve(9, 1, b"\xff", 16).0, AocStatus::InvalidUtf8);
+        assert_eq!(solve(9, 1, b"1 x", 16).0, AocStatus::InvalidInput);
+        assert_eq!(solve(1, 1, b"", 16).0, AocStatus::Ok);
+        assert_eq!(catch_panic(|| panic!("Solver bug")), Err(AocStatus::Panic));
+
+        let status =
+            unsafe { aoc_solve(9, 1, ptr::null(), 3, ptr::null_mut(), 0, ptr::null_mut()) };
+        assert_eq!(status, AocStatus::NullPointer);
+        let message = |status| unsafe { CStr::from_ptr(aoc_status_message(status)) };
+        assert_eq!(message(AocStatus::Panic as u32), c"The solver panicked");
+        assert_eq!(message(AocStatus::Ok as u32), c"ok");
+        assert_eq!(
+            AocStatus::from_u32(AocStatus::InvalidInput as u32),
+            Some(AocStatus::InvalidInput)
+        );
+        assert_eq!(message(8), c"Unknown status");
+        assert_eq!(message(u32::MAX), c"Unknown status");
+        assert_eq!(aoc_last_day(), 24);
+        assert_eq!(solve(22, 1, &input, 16).0, AocStatus::UnknownDay);
+    }
+
+    #[test]
+    fn test_header_is_up_to_date() {
+        let config = cbindgen::Config::from_file("cbindgen.toml").unwrap();
+        let mut header = Vec::new();
+        cbindgen::generate_with_config(".", config)
+            .unwrap()
+            .write(&mut header);
+        assert!(
+            fs::read("include/aoc.h").is_ok_and(|current| current == header),
+            "Regenerate the header, see cbindgen.toml"
+        );
+    }
+}
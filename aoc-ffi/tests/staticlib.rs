@@ -0,0 +1,44 @@
+//! Link a C program against `libaoc_ffi.a`, which cargo builds next to the
+//! test. The linker only keeps the object files of the library that the
+//! program refers to, so this checks that the days are still registered.
+
+#![cfg(unix)]
+
+use std::{env, fs, path::PathBuf, process::Command};
+
+const PROGRAM: &str = r#"
+#include <stdio.h>
+#include "aoc.h"
+
+int main(void) {
+    const char *input = "0 3 6 9 12 15\n";
+    char answer[16] = "";
+    AocStatus status = aoc_solve(9, 1, (const uint8_t *)input, 14, answer, 16, NULL);
+    printf("%u %u %s\n", aoc_last_day(), status, answer);
+    return 0;
+}
+"#;
+
+#[test]
+fn test_staticlib() {
+    let deps = env::current_exe().unwrap().parent().unwrap().to_path_buf();
+    let library = deps.join("libaoc_ffi.a");
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR"));
+    let source = dir.join("staticlib.c");
+    let program = dir.join("staticlib");
+    fs::write(&source, PROGRAM).unwrap();
+
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(compiler)
+        .arg("-Iinclude")
+        .arg(&source)
+        .arg(&library)
+        .args(["-lpthread", "-ldl", "-lm", "-o"])
+        .arg(&program)
+        .status()
+        .expect("Could not run the C compiler");
+    assert!(status.success());
+
+    let output = Command::new(&program).output().unwrap();
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), "24 0 18\n");
+}
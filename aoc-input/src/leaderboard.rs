@@ -0,0 +1,198 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use serde::Deserialize;
+
+use crate::InputError;
+
+/// adventofcode.com asks to fetch a private leaderboard at most every 15
+/// minutes.
+pub const LEADERBOARD_MAX_AGE: Duration = Duration::from_secs(15 * 60);
+
+/// A private leaderboard, as returned by its JSON API.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Leaderboard {
+    pub owner_id: u64,
+    pub event: String,
+    /// The members by their id.
+    pub members: BTreeMap<String, Member>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// Missing for anonymous users.
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    pub global_score: u32,
+    /// Unix timestamp of the last star, 0 if there are none.
+    pub last_star_ts: u64,
+    /// The stars by day and part.
+    pub completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct Star {
+    /// Unix timestamp of getting the star.
+    pub get_star_ts: u64,
+    pub star_index: u64,
+}
+
+impl Member {
+    /// The name, or how adventofcode.com shows anonymous users.
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// The number of stars of a day.
+    pub fn day_stars(&self, day: u8) -> usize {
+        self.completion_day_level.get(&day).map_or(0, BTreeMap::len)
+    }
+}
+
+impl Leaderboard {
+    /// The members ordered by local score. Ties go to the member who got
+    /// their last star first, like on the website.
+    pub fn ranking(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by_key(|m| (std::cmp::Reverse(m.local_score), m.last_star_ts, m.id));
+        members
+    }
+}
+
+pub fn parse_leaderboard(json: &str) -> Result<Leaderboard, InputError> {
+    serde_json::from_str(json).map_err(|e| InputError::LeaderboardParseError(e.to_string()))
+}
+
+/// Render the ranking as a table with a column per day, which shows `*` for
+/// both stars of the day and `+` for only the first one.
+pub fn leaderboard_table(leaderboard: &Leaderboard) -> String {
+    let ranking = leaderboard.ranking();
+    let rows: Vec<[String; 5]> = ranking
+        .iter()
+        .enumerate()
+        .map(|(rank, member)| {
+            let days: String = (1..=25)
+                .map(|day| match member.day_stars(day) {
+                    0 => '.',
+                    1 => '+',
+                    _ => '*',
+                })
+                .collect();
+            [
+                format!("{})", rank + 1),
+                member.local_score.to_string(),
+                member.stars.to_string(),
+                days,
+                member.display_name(),
+            ]
+        })
+        .collect();
+
+    // the day numbers end above their column
+    let header = ["", "Score", "Stars", "1   5   10   15   20   25", "Name"];
+    let mut widths = header.map(|h| h.chars().count());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let format_row = |cells: &[String]| -> String {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(widths)
+            .enumerate()
+            // right-align the numbers
+            .map(|(column, (cell, width))| match column {
+                0..=2 => format!("{cell:>width$}"),
+                _ => format!("{cell:<width$}"),
+            })
+            .collect();
+        format!("{}\n", cells.join("  ").trim_end())
+    };
+
+    let mut table = format_row(&header.map(String::from));
+    for row in &rows {
+        table.push_str(&format_row(row));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::leaderboard::*;
+
+    const LEADERBOARD: &str = r#"{
+        "owner_id": 1,
+        "event": "2023",
+        "members": {
+            "1": {
+                "id": 1, "name": "Alice", "stars": 3, "local_score": 10,
+                "global_score": 0, "last_star_ts": 1701500000,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1701409000, "star_index": 10},
+                        "2": {"get_star_ts": 1701409500, "star_index": 20}
+                    },
+                    "3": {"1": {"get_star_ts": 1701500000, "star_index": 30}}
+                }
+            },
+            "2": {
+                "id": 2, "name": null, "stars": 2, "local_score": 10,
+                "global_score": 0, "last_star_ts": 1701410000,
+                "completion_day_level": {
+                    "1": {
+                        "1": {"get_star_ts": 1701408000, "star_index": 5},
+                        "2": {"get_star_ts": 1701410000, "star_index": 25}
+                    }
+                }
+            },
+            "3": {
+                "id": 3, "name": "Bob", "stars": 0, "local_score": 0,
+                "global_score": 0, "last_star_ts": 0,
+                "completion_day_level": {}
+            }
+        }
+    }"#;
+
+    #[test]
+    fn test_parse_leaderboard() {
+        let leaderboard = parse_leaderboard(LEADERBOARD).unwrap();
+        assert_eq!(leaderboard.event, "2023");
+        let alice = &leaderboard.members["1"];
+        assert_eq!(alice.day_stars(1), 2);
+        assert_eq!(alice.day_stars(3), 1);
+        assert_eq!(alice.day_stars(2), 0);
+        assert_eq!(alice.completion_day_level[&3][&1].star_index, 30);
+        assert_eq!(
+            leaderboard.members["2"].display_name(),
+            "(anonymous user #2)"
+        );
+        assert!(matches!(
+            parse_leaderboard("{}"),
+            Err(InputError::LeaderboardParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_ranking() {
+        let leaderboard = parse_leaderboard(LEADERBOARD).unwrap();
+        let ids: Vec<u64> = leaderboard.ranking().iter().map(|m| m.id).collect();
+        assert_eq!(ids, [2, 1, 3]);
+    }
+
+    #[test]
+    fn test_leaderboard_table() {
+        let leaderboard = parse_leaderboard(LEADERBOARD).unwrap();
+        assert_eq!(
+            leaderboard_table(&leaderboard),
+            "    Score  Stars  1   5   10   15   20   25  Name\n\
+             1)     10      2  *........................  (anonymous user #2)\n\
+             2)     10      3  *.+......................  Alice\n\
+             3)      0      0  .........................  Bob\n"
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+use thiserror::Error;
+
+mod leaderboard;
+mod submit;
+
+pub use leaderboard::{
+    leaderboard_table, parse_leaderboard, Leaderboard, Member, Star, LEADERBOARD_MAX_AGE,
+};
+pub use submit::{parse_submit_response, SubmitResult};
+
+pub const YEAR: u16 = 2023;
+const BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/QuantumDancer/advent_of_code_2023 aoc-input";
+
+#[derive(Error, Debug)]
+pub enum InputError {
+    #[error("No session configured, set AOC_SESSION or `session` in config.toml")]
+    MissingSessionError,
+    #[error("Request to adventofcode.com failed: {0}")]
+    RequestError(String),
+    #[error("IO error: {0}")]
+    IoError(#[from] io::Error),
+    #[error("Could not parse the leaderboard: {0}")]
+    LeaderboardParseError(String),
+}
+
+/// Makes sure consecutive requests are at least `delay` apart, even across
+/// separate runs of the program, by remembering the time of the last request
+/// in a file.
+struct Throttle {
+    delay: Duration,
+    state_file: PathBuf,
+}
+
+impl Throttle {
+    fn wait(&self) -> Result<(), InputError> {
+        if let Ok(last_request) = fs::metadata(&self.state_file).and_then(|m| m.modified()) {
+            let elapsed = SystemTime::now()
+                .duration_since(last_request)
+                .unwrap_or_default();
+            if elapsed < self.delay {
+                thread::sleep(self.delay - elapsed);
+            }
+        }
+        fs::write(&self.state_file, "")?;
+        Ok(())
+    }
+}
+
+pub struct AocClient {
+    agent: ureq::Agent,
+    session: String,
+    throttle: Throttle,
+}
+
+impl AocClient {
+    pub fn new(session: String) -> AocClient {
+        AocClient {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            session,
+            throttle: Throttle {
+                delay: Duration::from_secs(5),
+                state_file: env::temp_dir().join("aoc-last-request"),
+            },
+        }
+    }
+
+    /// Create a client with the session cookie from the `AOC_SESSION`
+    /// environment variable.
+    pub fn from_env() -> Result<AocClient, InputError> {
+        let session = env::var("AOC_SESSION").map_err(|_| InputError::MissingSessionError)?;
+        Ok(AocClient::new(session))
+    }
+
+    pub fn with_delay(mut self, delay: Duration) -> AocClient {
+        self.throttle.delay = delay;
+        self
+    }
+
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String, InputError> {
+        self.throttle.wait()?;
+        self.agent
+            .get(&format!("{BASE_URL}/{year}/day/{day}/input"))
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| InputError::RequestError(e.to_string()))?
+            .into_string()
+            .map_err(InputError::IoError)
+    }
+
+    /// The JSON of the private leaderboard with the given id. Use
+    /// `cached_leaderboard` to not fetch it more often than allowed.
+    pub fn fetch_leaderboard(&self, year: u16, id: u64) -> Result<String, InputError> {
+        self.throttle.wait()?;
+        self.agent
+            .get(&format!(
+                "{BASE_URL}/{year}/leaderboard/private/view/{id}.json"
+            ))
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|e| InputError::RequestError(e.to_string()))?
+            .into_string()
+            .map_err(InputError::IoError)
+    }
+
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmitResult, InputError> {
+        self.throttle.wait()?;
+        let body = self
+            .agent
+            .post(&format!("{BASE_URL}/{year}/day/{day}/answer"))
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|e| InputError::RequestError(e.to_string()))?
+            .into_string()?;
+        Ok(parse_submit_response(&body))
+    }
+}
+
+/// Read the input of a day from the first of `paths` that exists, downloading
+/// it first if none does. The input is the same for both parts, but a day can
+/// have a file per part, so it is also written to every path that is missing.
+/// The client is only created if a download is necessary.
+pub fn cached_input(
+    year: u16,
+    day: u8,
+    paths: &[PathBuf],
+    client: impl FnOnce() -> Result<AocClient, InputError>,
+) -> Result<String, InputError> {
+    let input = match paths.iter().find(|path| path.exists()) {
+        Some(path) => fs::read_to_string(path)?,
+        None => client()?.fetch_input(year, day)?,
+    };
+    for path in paths.iter().filter(|path| !path.exists()) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, &input)?;
+    }
+    Ok(input)
+}
+
+/// Read a private leaderboard from the cache file at `path`, fetching it
+/// again if the file is older than `LEADERBOARD_MAX_AGE`. The client is only
+/// created if a request is necessary.
+pub fn cached_leaderboard(
+    year: u16,
+    id: u64,
+    path: &Path,
+    client: impl FnOnce() -> Result<AocClient, InputError>,
+) -> Result<Leaderboard, InputError> {
+    let age = fs::metadata(path)
+        .and_then(|m| m.modified())
+        .map(|modified| {
+            SystemTime::now()
+                .duration_since(modified)
+                .unwrap_or_default()
+        });
+    if matches!(age, Ok(age) if age < LEADERBOARD_MAX_AGE) {
+        return parse_leaderboard(&fs::read_to_string(path)?);
+    }
+    let json = client()?.fetch_leaderboard(year, id)?;
+    let leaderboard = parse_leaderboard(&json)?;
+    fs::write(path, &json)?;
+    Ok(leaderboard)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::process;
+
+    #[test]
+    fn test_cached_input_does_not_download() {
+        let path = env::temp_dir().join("aoc-input-test-cached.txt");
+        fs::write(&path, "cached").unwrap();
+        let client = || panic!("There should be no client needed");
+        let paths = [path.clone()];
+        assert_eq!(cached_input(YEAR, 1, &paths, client).unwrap(), "cached");
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_cached_input_per_part() {
+        let dir = env::temp_dir().join(format!("aoc-input-test-parts-{}", process::id()));
+        let paths = [dir.join("input_part1.txt"), dir.join("input_part2.txt")];
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&paths[1], "cached").unwrap();
+        let client = || panic!("There should be no client needed");
+        assert_eq!(cached_input(YEAR, 1, &paths, client).unwrap(), "cached");
+        assert_eq!(fs::read_to_string(&paths[0]).unwrap(), "cached");
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_cached_leaderboard_does_not_fetch() {
+        let path = env::temp_dir().join("aoc-input-test-leaderboard.json");
+        fs::write(&path, r#"{"owner_id": 7, "event": "2023", "members": {}}"#).unwrap();
+        let client = || panic!("There should be no client needed");
+        let leaderboard = cached_leaderboard(YEAR, 7, &path, client).unwrap();
+        assert_eq!(leaderboard.owner_id, 7);
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,112 @@
+use std::{fmt::Display, time::Duration};
+
+/// Outcome of submitting an answer, as reported by adventofcode.com.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SubmitResult {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    /// An answer was submitted too recently, the remaining wait time is
+    /// included if it could be parsed.
+    Wait(Option<Duration>),
+    AlreadySolved,
+    Unrecognized,
+}
+
+impl Display for SubmitResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmitResult::Correct => write!(f, "That's the right answer!"),
+            SubmitResult::TooHigh => write!(f, "That's not the right answer, it is too high"),
+            SubmitResult::TooLow => write!(f, "That's not the right answer, it is too low"),
+            SubmitResult::Incorrect => write!(f, "That's not the right answer"),
+            SubmitResult::Wait(Some(duration)) => {
+                write!(
+                    f,
+                    "Answer submitted too recently, wait {}s",
+                    duration.as_secs()
+                )
+            }
+            SubmitResult::Wait(None) => write!(f, "Answer submitted too recently"),
+            SubmitResult::AlreadySolved => write!(f, "This part is already solved"),
+            SubmitResult::Unrecognized => write!(f, "Could not understand the response"),
+        }
+    }
+}
+
+/// Interpret the HTML page returned after submitting an answer.
+pub fn parse_submit_response(body: &str) -> SubmitResult {
+    if body.contains("That's the right answer") {
+        SubmitResult::Correct
+    } else if body.contains("your answer is too high") {
+        SubmitResult::TooHigh
+    } else if body.contains("your answer is too low") {
+        SubmitResult::TooLow
+    } else if body.contains("That's not the right answer") {
+        SubmitResult::Incorrect
+    } else if body.contains("You gave an answer too recently") {
+        SubmitResult::Wait(parse_wait_time(body))
+    } else if body.contains("You don't seem to be solving the right level") {
+        SubmitResult::AlreadySolved
+    } else {
+        SubmitResult::Unrecognized
+    }
+}
+
+/// Parse "You have 1m 30s left to wait" into a duration.
+fn parse_wait_time(body: &str) -> Option<Duration> {
+    let (_, rest) = body.split_once("You have ")?;
+    let (wait_time, _) = rest.split_once(" left to wait")?;
+    let mut seconds = 0;
+    for part in wait_time.split(' ') {
+        if let Some(minutes) = part.strip_suffix('m') {
+            seconds += minutes.parse::<u64>().ok()? * 60;
+        } else if let Some(secs) = part.strip_suffix('s') {
+            seconds += secs.parse::<u64>().ok()?;
+        } else {
+            return None;
+        }
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::submit::*;
+
+    #[test]
+    fn test_parse_submit_response() {
+        let tests = [
+            ("<p>That's the right answer! You are one gold star closer.</p>", SubmitResult::Correct),
+            (
+                "<p>That's not the right answer; your answer is too high.</p>",
+                SubmitResult::TooHigh,
+            ),
+            (
+                "<p>That's not the right answer; your answer is too low.</p>",
+                SubmitResult::TooLow,
+            ),
+            (
+                "<p>That's not the right answer. If you're stuck, ...</p>",
+                SubmitResult::Incorrect,
+            ),
+            (
+                "<p>You gave an answer too recently. You have 1m 5s left to wait.</p>",
+                SubmitResult::Wait(Some(Duration::from_secs(65))),
+            ),
+            (
+                "<p>You gave an answer too recently. You have 12s left to wait.</p>",
+                SubmitResult::Wait(Some(Duration::from_secs(12))),
+            ),
+            (
+                "<p>You don't seem to be solving the right level.  Did you already complete it?</p>",
+                SubmitResult::AlreadySolved,
+            ),
+            ("<html></html>", SubmitResult::Unrecognized),
+        ];
+        for (input, expected) in tests {
+            assert_eq!(parse_submit_response(input), expected);
+        }
+    }
+}
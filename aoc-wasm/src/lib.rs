@@ -0,0 +1,52 @@
+//! WebAssembly bindings of the solutions, e.g. for a browser playground.
+//! Build them with `wasm-pack build aoc-wasm --target web`.
+
+use wasm_bindgen::prelude::*;
+
+/// The year of the days that the bindings solve.
+const YEAR: u16 = 2023;
+
+/// The days that can be solved.
+#[wasm_bindgen]
+pub fn days() -> Vec<u8> {
+    aoc_days::days_of(YEAR).iter().map(|day| day.day).collect()
+}
+
+/// Solve `part` of `day` for the puzzle input. Errors are thrown with their
+/// message.
+#[wasm_bindgen]
+pub fn solve(day: u8, part: u8, input: &str) -> Result<String, String> {
+    let Some(registered) = aoc_days::day(YEAR, day) else {
+        return Err(format!("Day {day} is not implemented"));
+    };
+    if !(1..=2).contains(&part) {
+        return Err(format!("Part {part} does not exist"));
+    }
+    let parsed = registered.parse(input).map_err(|e| e.to_string())?;
+    let answer = match part {
+        1 => parsed.part1(),
+        _ => parsed.part2(),
+    };
+    answer.map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn test_solve() {
+        let input =
+            fs::read_to_string("../2023/day_09/input_test.txt").expect("Could not read the file");
+        assert_eq!(solve(9, 1, &input).unwrap(), "114");
+        assert_eq!(solve(9, 2, &input).unwrap(), "2");
+        assert_eq!(solve(9, 3, &input).unwrap_err(), "Part 3 does not exist");
+        assert_eq!(
+            solve(26, 1, &input).unwrap_err(),
+            "Day 26 is not implemented"
+        );
+        assert!(solve(9, 1, "1 x").unwrap_err().starts_with("Line 1"));
+        assert_eq!(days().len(), 22);
+    }
+}
@@ -0,0 +1,155 @@
+use std::{collections::HashMap, env, fs, io, path::Path, path::PathBuf};
+
+use aoc_input::{AocClient, InputError, YEAR};
+use clap::ValueEnum;
+use serde::Deserialize;
+
+use crate::{report::OutputFormat, RunnerError};
+
+/// Settings of the runner. They are read from `config.toml`, and can be
+/// overridden by `AOC_*` variables set in the environment or in `.env`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Session cookie of adventofcode.com, `AOC_SESSION`
+    pub session: Option<String>,
+    /// Year of the commands if `--year` is omitted, `AOC_YEAR`
+    pub year: u16,
+    /// Directory containing the `{year}/day_XX` input directories,
+    /// `AOC_INPUT_DIR`
+    pub input_dir: PathBuf,
+    /// Output format of `run` if `--format` is omitted, `AOC_FORMAT`
+    pub format: OutputFormat,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            year: YEAR,
+            input_dir: PathBuf::from("."),
+            format: OutputFormat::Plain,
+        }
+    }
+}
+
+impl Config {
+    /// Load `config.toml` and `.env` from `dir`, both are optional. Variables
+    /// of the process environment take precedence over the ones in `.env`.
+    pub fn load(dir: &Path) -> Result<Config, RunnerError> {
+        let config_file = read_optional(&dir.join("config.toml"))?;
+        let dotenv = read_optional(&dir.join(".env"))?
+            .map(|content| parse_dotenv(&content))
+            .unwrap_or_default();
+        Config::from_sources(config_file.as_deref(), |key| {
+            env::var(key).ok().or_else(|| dotenv.get(key).cloned())
+        })
+    }
+
+    fn from_sources(
+        config_file: Option<&str>,
+        var: impl Fn(&str) -> Option<String>,
+    ) -> Result<Config, RunnerError> {
+        let mut config: Config = match config_file {
+            Some(content) => {
+                toml::from_str(content).map_err(|e| RunnerError::ConfigError(e.to_string()))?
+            }
+            None => Config::default(),
+        };
+
+        if let Some(session) = var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Some(year) = var("AOC_YEAR") {
+            config.year = year
+                .parse()
+                .map_err(|_| RunnerError::ConfigError(format!("Invalid AOC_YEAR `{year}`")))?;
+        }
+        if let Some(input_dir) = var("AOC_INPUT_DIR") {
+            config.input_dir = input_dir.into();
+        }
+        if let Some(format) = var("AOC_FORMAT") {
+            config.format = OutputFormat::from_str(&format, true)
+                .map_err(|_| RunnerError::ConfigError(format!("Invalid AOC_FORMAT `{format}`")))?;
+        }
+        Ok(config)
+    }
+
+    /// Client for adventofcode.com using the configured session.
+    pub fn client(&self) -> Result<AocClient, InputError> {
+        let session = self
+            .session
+            .clone()
+            .ok_or(InputError::MissingSessionError)?;
+        Ok(AocClient::new(session))
+    }
+}
+
+fn read_optional(path: &Path) -> Result<Option<String>, RunnerError> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(RunnerError::ReadInputError(path.to_path_buf(), e)),
+    }
+}
+
+/// Parse `KEY=value` lines, ignoring empty lines and `#` comments.
+fn parse_dotenv(content: &str) -> HashMap<String, String> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| line.strip_prefix("export ").unwrap_or(line).split_once('='))
+        .map(|(key, value)| {
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value);
+            (key.trim().to_string(), value.to_string())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::*;
+
+    #[test]
+    fn test_config_overrides() {
+        let config_file = r#"
+            session = "from-file"
+            year = 2022
+            format = "json"
+        "#;
+        let vars = HashMap::from([("AOC_SESSION", "from-env"), ("AOC_INPUT_DIR", "inputs")]);
+        let config = Config::from_sources(Some(config_file), |key| {
+            vars.get(key).map(|v| v.to_string())
+        })
+        .unwrap();
+        assert_eq!(
+            config,
+            Config {
+                session: Some("from-env".to_string()),
+                year: 2022,
+                input_dir: PathBuf::from("inputs"),
+                format: OutputFormat::Json,
+            }
+        );
+
+        assert_eq!(
+            Config::from_sources(None, |_| None).unwrap(),
+            Config::default()
+        );
+        assert!(Config::from_sources(None, |_| Some("x".to_string())).is_err());
+        assert!(Config::from_sources(Some("unknown = 1"), |_| None).is_err());
+    }
+
+    #[test]
+    fn test_parse_dotenv() {
+        let dotenv = parse_dotenv("# comment\n\nAOC_SESSION=\"abc\"\nexport AOC_YEAR = 2023\n");
+        assert_eq!(dotenv.len(), 2);
+        assert_eq!(dotenv["AOC_SESSION"], "abc");
+        assert_eq!(dotenv["AOC_YEAR"], "2023");
+    }
+}
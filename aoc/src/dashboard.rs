@@ -0,0 +1,290 @@
+use std::{
+    collections::BTreeMap,
+    io,
+    path::Path,
+    sync::mpsc::{self, Receiver},
+    thread,
+    time::{Duration, Instant},
+};
+
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEventKind},
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Bar, BarChart, BarGroup, Block, Cell, Row, Table},
+    DefaultTerminal, Frame,
+};
+
+use crate::{
+    days,
+    report::{format_duration, DayRun, TimedAnswer},
+    run_each,
+    verify::{AnswersFile, InputKind},
+    RunnerError,
+};
+
+/// State of the dashboard: the days that are done so far, checked against
+/// the expected answers of the real inputs.
+pub struct Dashboard {
+    year: u16,
+    answers: AnswersFile,
+    /// The days that are run.
+    days: Vec<u8>,
+    runs: BTreeMap<u8, DayRun>,
+    start: Instant,
+    /// Time until all days were done.
+    total: Option<Duration>,
+}
+
+impl Dashboard {
+    pub fn new(year: u16, answers: AnswersFile) -> Dashboard {
+        Dashboard {
+            year,
+            answers,
+            days: days(year),
+            runs: BTreeMap::new(),
+            start: Instant::now(),
+            total: None,
+        }
+    }
+
+    pub fn add(&mut self, run: DayRun) {
+        self.runs.insert(run.day, run);
+        if self.total.is_none() && self.runs.len() == self.days.len() {
+            self.total = Some(self.start.elapsed());
+        }
+    }
+
+    pub fn is_done(&self) -> bool {
+        self.total.is_some()
+    }
+
+    fn check(&self, answer: Option<&TimedAnswer>) -> Cell<'static> {
+        let Some(answer) = answer else {
+            return Cell::default();
+        };
+        match self
+            .answers
+            .expected(answer.day, InputKind::Real, answer.part)
+        {
+            Some(expected) if *expected == answer.answer => Cell::from("ok").green(),
+            Some(_) => Cell::from("FAIL").red(),
+            None => Cell::from("?"),
+        }
+    }
+
+    fn row(&self, day: u8) -> Row<'static> {
+        let mut cells = vec![Cell::from(format!("{day:02}"))];
+        match self.runs.get(&day).map(|run| &run.answers) {
+            None => cells.push(Cell::from("running").dark_gray()),
+            Some(Err(RunnerError::ReadInputError(..))) => {
+                cells.push(Cell::from("no input").dark_gray())
+            }
+            Some(Err(e)) => cells.push(Cell::from(format!("error: {e}")).red()),
+            Some(Ok(answers)) => {
+                let answer = |part| answers.iter().find(|a| a.part == part);
+                let duration = |duration| Cell::from(format_duration(duration));
+                for part in 1..=2 {
+                    let text = answer(part).map(|a| a.answer.clone()).unwrap_or_default();
+                    cells.push(Cell::from(text));
+                    cells.push(self.check(answer(part)));
+                }
+                cells.extend(answers.first().map(|a| duration(a.parse_time)));
+                cells.extend(
+                    (1..=2).filter_map(|part| answer(part).map(|a| duration(a.solve_time))),
+                );
+            }
+        }
+        if let Some(run) = self.runs.get(&day) {
+            cells.resize(8, Cell::default());
+            cells.push(Cell::from(format_duration(run.wall_time)));
+        }
+        Row::new(cells)
+    }
+
+    pub fn render(&self, frame: &mut Frame) {
+        let [main, footer] =
+            Layout::vertical([Constraint::Fill(1), Constraint::Length(1)]).areas(frame.area());
+        let [table_area, chart_area] =
+            Layout::horizontal([Constraint::Percentage(70), Constraint::Percentage(30)])
+                .areas(main);
+
+        let header = Row::new([
+            "Day", "Part 1", "", "Part 2", "", "Parse", "Solve 1", "Solve 2", "Total",
+        ])
+        .bold();
+        let widths = [
+            Constraint::Length(3),
+            Constraint::Fill(1),
+            Constraint::Length(4),
+            Constraint::Fill(1),
+            Constraint::Length(4),
+            Constraint::Length(8),
+            Constraint::Length(8),
+            Constraint::Length(8),
+            Constraint::Length(8),
+        ];
+        let table = Table::new(self.days.iter().map(|&day| self.row(day)), widths)
+            .header(header)
+            .block(Block::bordered().title(format!("Advent of Code {}", self.year)));
+        frame.render_widget(table, table_area);
+
+        // one bar per line, next to the row of the day in the table
+        let bars: Vec<Bar> = self
+            .days
+            .iter()
+            .map(|&day| {
+                let run = self.runs.get(&day);
+                let time = run.map(|run| run.wall_time).unwrap_or_default();
+                Bar::default()
+                    .label(Line::from(format!("{day:02}")))
+                    .value(time.as_micros() as u64)
+                    .text_value(run.map(|_| format_duration(time)).unwrap_or_default())
+            })
+            .collect();
+        let chart = BarChart::default()
+            .direction(Direction::Horizontal)
+            .data(BarGroup::default().bars(&bars))
+            .bar_width(1)
+            .bar_gap(0)
+            .bar_style(Style::default().fg(Color::Blue))
+            .block(Block::bordered().title("Runtime"));
+        // skip the header line of the table
+        let mut chart_area = chart_area;
+        chart_area.y += 1;
+        chart_area.height = chart_area.height.saturating_sub(1);
+        frame.render_widget(chart, chart_area);
+
+        let status = match self.total {
+            Some(total) => format!("Total runtime: {}", format_duration(total)),
+            None => format!("Running, {}/{} days done", self.runs.len(), self.days.len()),
+        };
+        frame.render_widget(Line::from(format!("{status} | q to quit")), footer);
+    }
+}
+
+/// Run every day of `year` on its default input in the background, with the
+/// input files located relative to `root`, and show the results as they come
+/// in until `q` or Esc is pressed.
+pub fn show(root: &Path, year: u16, parallel: bool, answers: AnswersFile) -> io::Result<()> {
+    let (sender, receiver) = mpsc::channel();
+    let root = root.to_path_buf();
+    thread::spawn(move || {
+        // the receiver is gone if the dashboard was closed early
+        run_each(&root, year, parallel, |run| {
+            sender.send(run).unwrap_or_default()
+        })
+    });
+
+    let mut dashboard = Dashboard::new(year, answers);
+    let mut terminal = ratatui::init();
+    let result = event_loop(&mut terminal, &mut dashboard, &receiver);
+    ratatui::restore();
+    result
+}
+
+fn event_loop(
+    terminal: &mut DefaultTerminal,
+    dashboard: &mut Dashboard,
+    receiver: &Receiver<DayRun>,
+) -> io::Result<()> {
+    loop {
+        for run in receiver.try_iter() {
+            dashboard.add(run);
+        }
+        terminal.draw(|frame| dashboard.render(frame))?;
+        if event::poll(Duration::from_millis(100))? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press
+                    && matches!(key.code, KeyCode::Char('q') | KeyCode::Esc)
+                {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dashboard::*;
+    use ratatui::{backend::TestBackend, Terminal};
+    use std::io;
+
+    fn screen(dashboard: &Dashboard) -> Vec<String> {
+        let mut terminal = Terminal::new(TestBackend::new(120, 26)).unwrap();
+        terminal.draw(|frame| dashboard.render(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|line| line.iter().map(|cell| cell.symbol()).collect())
+            .collect()
+    }
+
+    fn answer(part: u8, answer: &str) -> TimedAnswer {
+        TimedAnswer {
+            day: 4,
+            part,
+            answer: answer.to_string(),
+            parse_time: Duration::from_micros(20),
+            solve_time: Duration::from_micros(3),
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let answers: AnswersFile = toml::from_str(
+            r#"
+            [[days]]
+            day = 4
+            real = { part1 = "13", part2 = "31" }
+            "#,
+        )
+        .unwrap();
+        let mut dashboard = Dashboard::new(2023, answers);
+        dashboard.add(DayRun {
+            day: 4,
+            answers: Ok(vec![answer(1, "13"), answer(2, "30")]),
+            wall_time: Duration::from_micros(30),
+        });
+        dashboard.add(DayRun {
+            day: 5,
+            answers: Err(RunnerError::UnknownDay(2023, 5)),
+            wall_time: Duration::from_micros(1),
+        });
+
+        dashboard.add(DayRun {
+            day: 6,
+            answers: Err(RunnerError::ReadInputError(
+                "2023/day_06/input.txt".into(),
+                io::ErrorKind::NotFound.into(),
+            )),
+            wall_time: Duration::from_micros(1),
+        });
+
+        let screen = screen(&dashboard);
+        let line = |day: &str| {
+            screen
+                .iter()
+                .find(|line| line.trim_start_matches(['│', ' ']).starts_with(day))
+                .unwrap()
+        };
+        let day_4: Vec<&str> = line("04").split_whitespace().collect();
+        assert_eq!(
+            &day_4[1..9],
+            ["13", "ok", "30", "FAIL", "20.0µs", "3.0µs", "3.0µs", "30.0µs"]
+        );
+        // the message is cut off at the width of the column
+        assert!(line("05").contains("error: Day 5"));
+        assert!(line("06").contains("no input"));
+        assert!(line("07").contains("running"));
+        assert!(screen
+            .last()
+            .unwrap()
+            .starts_with("Running, 3/22 days done"));
+        assert!(screen[0].contains("Advent of Code 2023"));
+        assert!(!dashboard.is_done());
+    }
+}
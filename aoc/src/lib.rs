@@ -0,0 +1,290 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::Instant,
+};
+
+use aoc_common::{MergeError, SolveError};
+use aoc_days::{ParsedInput, RegisteredDay};
+use aoc_input::InputError;
+use rayon::prelude::*;
+use report::{DayRun, TimedAnswer};
+use thiserror::Error;
+use tracing::info_span;
+
+pub mod config;
+#[cfg(feature = "dashboard")]
+pub mod dashboard;
+#[cfg(feature = "profiling")]
+pub mod profile;
+pub mod report;
+pub mod serve;
+pub mod verify;
+
+/// All days of `year` that are implemented, in order.
+pub fn days(year: u16) -> Vec<u8> {
+    aoc_days::days_of(year).iter().map(|day| day.day).collect()
+}
+
+/// All years with implemented days, in order.
+pub fn years() -> Vec<u16> {
+    aoc_days::years()
+}
+
+fn registered(year: u16, day: u8) -> Result<&'static RegisteredDay, RunnerError> {
+    aoc_days::day(year, day).ok_or(RunnerError::UnknownDay(year, day))
+}
+
+#[derive(Error, Debug)]
+pub enum RunnerError {
+    #[error("Day {1} of {0} is not implemented")]
+    UnknownDay(u16, u8),
+    #[error("Could not read input file `{0}`: {1}")]
+    ReadInputError(PathBuf, io::Error),
+    #[error("Could not merge inputs: {0}")]
+    MergeError(#[from] MergeError),
+    #[error("{0}")]
+    SolveError(SolveError),
+    #[error(transparent)]
+    InputError(#[from] InputError),
+    #[error("Could not parse answers file: {0}")]
+    AnswersParseError(String),
+    #[error("Invalid configuration: {0}")]
+    ConfigError(String),
+    #[error("Server error: {0}")]
+    ServeError(io::Error),
+    #[cfg(feature = "dashboard")]
+    #[error("Dashboard error: {0}")]
+    DashboardError(io::Error),
+    #[cfg(feature = "profiling")]
+    #[error("Profiling failed: {0}")]
+    ProfilingError(String),
+}
+
+impl From<SolveError> for RunnerError {
+    fn from(error: SolveError) -> Self {
+        match error.downcast::<MergeError>() {
+            Ok(error) => RunnerError::MergeError(*error),
+            Err(error) => RunnerError::SolveError(error),
+        }
+    }
+}
+
+/// Answers for the requested parts, in order.
+pub type Answers = Vec<String>;
+
+/// Path of the puzzle input of a day, relative to the workspace root. The
+/// days of a year are in its directory, like `2023/day_05`.
+pub fn default_input(year: u16, day: u8, part: Option<u8>) -> PathBuf {
+    match (year, day) {
+        // day 1 of 2023 was the only day with separate input files per part
+        (2023, 1) => format!("{year}/day_01/input_part{}.txt", part.unwrap_or(1)).into(),
+        _ => format!("{year}/day_{day:02}/input.txt").into(),
+    }
+}
+
+/// Run the given part of a day, or both parts if no part is given. Multiple
+/// inputs are merged into a single dataset before solving.
+pub fn run(
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    inputs: &[String],
+) -> Result<Answers, RunnerError> {
+    solve(registered(year, day)?, part, inputs)
+}
+
+/// Like `run`, but parses and solves each part separately so that the time
+/// spent in each step can be reported.
+pub fn run_timed(
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    inputs: &[String],
+) -> Result<Vec<TimedAnswer>, RunnerError> {
+    solve_timed(registered(year, day)?, part, inputs)
+}
+
+/// Run both parts of every implemented day of `year` on its default input,
+/// with the input files located relative to the workspace `root`. With
+/// `parallel`, the days are run on the rayon thread pool; the results are
+/// still in day order.
+pub fn run_all(root: &Path, year: u16, parallel: bool) -> Vec<DayRun> {
+    let runs = Mutex::new(Vec::new());
+    run_each(root, year, parallel, |run| runs.lock().unwrap().push(run));
+    let mut runs = runs.into_inner().unwrap();
+    runs.sort_by_key(|run| run.day);
+    runs
+}
+
+/// Like `run_all`, but hands every run to `on_run` as soon as it is done,
+/// e.g. to show the progress. With `parallel`, the runs are not in day order.
+pub fn run_each(root: &Path, year: u16, parallel: bool, on_run: impl Fn(DayRun) + Sync) {
+    if parallel {
+        days(year)
+            .into_par_iter()
+            .for_each(|day| on_run(run_day(root, year, day)));
+    } else {
+        days(year)
+            .into_iter()
+            .for_each(|day| on_run(run_day(root, year, day)));
+    }
+}
+
+fn run_day(root: &Path, year: u16, day: u8) -> DayRun {
+    let start = Instant::now();
+    let path = root.join(default_input(year, day, None));
+    let answers = fs::read_to_string(&path)
+        .map_err(|e| RunnerError::ReadInputError(path, e))
+        .and_then(|input| run_timed(year, day, None, &[input]));
+    DayRun {
+        day,
+        answers,
+        wall_time: start.elapsed(),
+    }
+}
+
+fn parse_merged<'a>(
+    day: &RegisteredDay,
+    inputs: &'a [String],
+) -> Result<Box<dyn ParsedInput + 'a>, RunnerError> {
+    let inputs: Vec<&str> = inputs.iter().map(String::as_str).collect();
+    Ok(day.parse_merged(&inputs)?)
+}
+
+fn parts(part: Option<u8>) -> Vec<u8> {
+    match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    }
+}
+
+fn solve_part(parsed: &dyn ParsedInput, part: u8) -> Result<String, RunnerError> {
+    let _part_span = info_span!("part", part).entered();
+    let answer = match part {
+        1 => parsed.part1(),
+        _ => parsed.part2(),
+    };
+    Ok(answer?)
+}
+
+fn solve_timed(
+    day: &RegisteredDay,
+    part: Option<u8>,
+    inputs: &[String],
+) -> Result<Vec<TimedAnswer>, RunnerError> {
+    let _day_span = info_span!("day", year = day.year, day = day.day).entered();
+    let start = Instant::now();
+    let parsed = info_span!("parse").in_scope(|| parse_merged(day, inputs))?;
+    let parse_time = start.elapsed();
+
+    parts(part)
+        .into_iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = solve_part(parsed.as_ref(), part)?;
+            Ok(TimedAnswer {
+                day: day.day,
+                part,
+                answer,
+                parse_time,
+                solve_time: start.elapsed(),
+            })
+        })
+        .collect()
+}
+
+fn solve(day: &RegisteredDay, part: Option<u8>, inputs: &[String]) -> Result<Answers, RunnerError> {
+    let _day_span = info_span!("day", year = day.year, day = day.day).entered();
+    if let ([input], None) = (inputs, part) {
+        let (part1, part2) = info_span!("both").in_scope(|| day.solve_both(input))?;
+        return Ok(vec![part1, part2]);
+    }
+
+    let parsed = info_span!("parse").in_scope(|| parse_merged(day, inputs))?;
+    parts(part)
+        .into_iter()
+        .map(|part| solve_part(parsed.as_ref(), part))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn test_input(day: u8) -> String {
+        fs::read_to_string(format!("../2023/day_{day:02}/input_test.txt"))
+            .expect("Could not read the file")
+    }
+
+    #[test]
+    fn test_run() {
+        assert_eq!(
+            run(2023, 3, None, &[test_input(3)]).unwrap(),
+            vec!["4361", "467835"]
+        );
+        assert_eq!(run(2023, 4, Some(2), &[test_input(4)]).unwrap(), vec!["30"]);
+        assert_eq!(run(2023, 5, Some(1), &[test_input(5)]).unwrap(), vec!["35"]);
+        let timed = run_timed(2023, 4, None, &[test_input(4)]).unwrap();
+        assert_eq!(
+            timed
+                .iter()
+                .map(|t| (t.part, t.answer.as_str()))
+                .collect::<Vec<_>>(),
+            vec![(1, "13"), (2, "30")]
+        );
+        assert!(matches!(
+            run(2023, 26, None, &[]),
+            Err(RunnerError::UnknownDay(2023, 26))
+        ));
+        assert!(matches!(
+            run(2015, 1, None, &[]),
+            Err(RunnerError::UnknownDay(2015, 1))
+        ));
+    }
+
+    #[test]
+    fn test_run_all() {
+        assert_eq!(years(), [2023]);
+        let runs = run_all(Path::new(".."), 2023, false);
+        assert_eq!(runs.len(), days(2023).len());
+        // not every input is checked in, the other days need to succeed
+        for run in &runs {
+            match &run.answers {
+                Err(RunnerError::ReadInputError(path, _)) => assert!(!path.exists()),
+                answers => assert!(answers.is_ok(), "day {}", run.day),
+            }
+        }
+
+        let parallel_runs = run_all(Path::new(".."), 2023, true);
+        let answers = |runs: &[DayRun]| -> Vec<Option<Vec<String>>> {
+            runs.iter()
+                .map(|run| {
+                    let answers = run.answers.as_ref().ok()?;
+                    Some(answers.iter().map(|a| a.answer.clone()).collect())
+                })
+                .collect()
+        };
+        assert_eq!(answers(&parallel_runs), answers(&runs));
+        assert!(parallel_runs.windows(2).all(|w| w[0].day < w[1].day));
+    }
+
+    #[test]
+    fn test_run_merged() {
+        let inputs = [test_input(4), test_input(4)];
+        assert_eq!(run(2023, 4, None, &inputs).unwrap(), vec!["26", "60"]);
+        assert!(matches!(
+            run(2023, 3, None, &[test_input(3), test_input(3)]),
+            Err(RunnerError::MergeError(MergeError::Unsupported))
+        ));
+    }
+
+    #[test]
+    fn test_run_invalid_input() {
+        let error = run(2023, 9, None, &["1 x".to_string()]).unwrap_err();
+        assert!(matches!(error, RunnerError::SolveError(_)));
+        assert!(run(2023, 9, Some(1), &["1 x".to_string()]).is_err());
+        assert!(run_timed(2023, 9, None, &["1 x".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,239 @@
+use std::{
+    env, fs,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    process,
+    time::Instant,
+};
+
+use aoc::{
+    config::Config,
+    default_input,
+    report::{summary_table, to_csv, to_json, OutputFormat},
+    run, run_all, run_timed, serve,
+    verify::{load_answers, verify},
+    RunnerError,
+};
+use aoc_common::setup_tracing;
+use aoc_input::{cached_input, cached_leaderboard, leaderboard_table};
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(about = "Run the Advent of Code solutions")]
+struct Cli {
+    /// Year of the puzzles, defaults to the configured year
+    #[arg(long, global = true)]
+    year: Option<u16>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day
+    Run {
+        #[arg(long)]
+        day: u8,
+        /// Part to run, both parts are run if omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file(s), defaults to the input file of the day
+        #[arg(long)]
+        input: Vec<PathBuf>,
+        /// Solve all input files as one merged dataset
+        #[arg(long)]
+        merge_inputs: bool,
+        /// Output format, json and csv include parse and solve times.
+        /// Defaults to the configured format
+        #[arg(long, value_enum)]
+        format: Option<OutputFormat>,
+        /// Write a CPU flamegraph of each part to the current directory
+        #[cfg(feature = "profiling")]
+        #[arg(long)]
+        profile: bool,
+    },
+    /// Run every implemented day and print a summary table
+    RunAll {
+        /// Run the days in parallel
+        #[arg(long)]
+        parallel: bool,
+    },
+    /// Run every implemented day and show the results in a live dashboard
+    #[cfg(feature = "dashboard")]
+    Dashboard {
+        /// Run the days in parallel
+        #[arg(long)]
+        parallel: bool,
+        /// Expected answers to check the results against, defaults to the
+        /// `answers.toml` of the year in the current directory, like
+        /// `2023/answers.toml`
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Solve a part and submit the answer, using the configured session
+    Submit {
+        #[arg(long)]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Input file, defaults to the input file of the day
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Check all implemented days against the expected answers
+    Verify {
+        /// Defaults to the `answers.toml` of the year in the current
+        /// directory, like `2023/answers.toml`
+        #[arg(long)]
+        answers: Option<PathBuf>,
+    },
+    /// Download the puzzle input of a day, using the configured session. Days
+    /// with a file per part get the input in each of them
+    Download {
+        #[arg(long)]
+        day: u8,
+    },
+    /// Show a private leaderboard, using the configured session. It is
+    /// fetched at most every 15 minutes, in between a cached copy is shown
+    Leaderboard {
+        /// Id of the leaderboard, the number at the end of its URL
+        #[arg(long)]
+        id: u64,
+    },
+    /// Serve the solutions of all years over HTTP, e.g.
+    /// `POST /2023/day/4/part/1` with the puzzle input as body
+    Serve {
+        #[arg(long, default_value = "127.0.0.1:3000")]
+        addr: SocketAddr,
+    },
+}
+
+fn main() -> Result<(), RunnerError> {
+    let cli = Cli::parse();
+    if env::var_os("RUST_LOG").is_some() {
+        setup_tracing();
+    }
+    let config = Config::load(Path::new("."))?;
+    let year = cli.year.unwrap_or(config.year);
+    // the answers are checked in next to the days, not with the inputs
+    let answers_path = |answers: Option<PathBuf>| {
+        answers.unwrap_or_else(|| PathBuf::from(format!("{year}/answers.toml")))
+    };
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            merge_inputs,
+            format,
+            #[cfg(feature = "profiling")]
+            profile,
+        } => {
+            let format = format.unwrap_or(config.format);
+            let paths = if input.is_empty() {
+                vec![config.input_dir.join(default_input(year, day, part))]
+            } else {
+                input
+            };
+            let inputs = paths
+                .into_iter()
+                .map(|path| {
+                    fs::read_to_string(&path).map_err(|e| RunnerError::ReadInputError(path, e))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let runs: Vec<&[String]> = if merge_inputs {
+                vec![&inputs]
+            } else {
+                inputs.chunks(1).collect()
+            };
+            #[cfg(feature = "profiling")]
+            if profile {
+                for inputs in runs {
+                    for part in part.map_or(vec![1, 2], |part| vec![part]) {
+                        let name = format!("{year}_day_{day:02}_part{part}");
+                        let answers = aoc::profile::profile(Path::new("."), &name, || {
+                            run(year, day, Some(part), inputs)
+                        })?;
+                        for answer in answers {
+                            println!("{answer}");
+                        }
+                    }
+                }
+                return Ok(());
+            }
+
+            if format == OutputFormat::Plain {
+                for inputs in runs {
+                    for answer in run(year, day, part, inputs)? {
+                        println!("{answer}");
+                    }
+                }
+            } else {
+                let mut answers = Vec::new();
+                for inputs in runs {
+                    answers.extend(run_timed(year, day, part, inputs)?);
+                }
+                match format {
+                    OutputFormat::Json => println!("{}", to_json(&answers)),
+                    _ => print!("{}", to_csv(&answers)),
+                }
+            }
+        }
+        Command::RunAll { parallel } => {
+            let start = Instant::now();
+            let runs = run_all(&config.input_dir, year, parallel);
+            print!("{}", summary_table(&runs, start.elapsed()));
+        }
+        #[cfg(feature = "dashboard")]
+        Command::Dashboard { parallel, answers } => {
+            let answers = load_answers(&answers_path(answers))?;
+            aoc::dashboard::show(&config.input_dir, year, parallel, answers)
+                .map_err(RunnerError::DashboardError)?;
+        }
+        Command::Submit { day, part, input } => {
+            let client = config.client()?;
+            let path = input
+                .unwrap_or_else(|| config.input_dir.join(default_input(year, day, Some(part))));
+            let input =
+                fs::read_to_string(&path).map_err(|e| RunnerError::ReadInputError(path, e))?;
+            let answer = run(year, day, Some(part), &[input])?.remove(0);
+            println!("Submitting {answer}");
+            let result = client.submit(year, day, part, &answer)?;
+            println!("{result}");
+        }
+        Command::Verify { answers } => {
+            let answers = load_answers(&answers_path(answers))?;
+            let verifications = verify(&answers, &config.input_dir, year);
+            for verification in &verifications {
+                println!("{verification}");
+            }
+            if !verifications.iter().all(|v| v.passed()) {
+                process::exit(1);
+            }
+        }
+        Command::Download { day } => {
+            let mut paths: Vec<PathBuf> = [1, 2]
+                .map(|part| config.input_dir.join(default_input(year, day, Some(part))))
+                .into();
+            paths.dedup();
+            cached_input(year, day, &paths, || config.client())?;
+            for path in paths {
+                println!("{}", path.display());
+            }
+        }
+        Command::Leaderboard { id } => {
+            let path = env::temp_dir().join(format!("aoc-leaderboard-{year}-{id}.json"));
+            let leaderboard = cached_leaderboard(year, id, &path, || config.client())?;
+            print!("{}", leaderboard_table(&leaderboard));
+        }
+        Command::Serve { addr } => {
+            let runtime = tokio::runtime::Runtime::new().map_err(RunnerError::ServeError)?;
+            println!("Listening on http://{addr}");
+            runtime
+                .block_on(serve::serve(addr))
+                .map_err(RunnerError::ServeError)?;
+        }
+    }
+    Ok(())
+}
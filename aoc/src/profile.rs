@@ -0,0 +1,32 @@
+use std::{fs::File, path::Path};
+
+use crate::RunnerError;
+
+/// Run `f` under the pprof CPU profiler and write a flamegraph of it to
+/// `<dir>/<name>.svg`.
+pub fn profile<T>(
+    dir: &Path,
+    name: &str,
+    f: impl FnOnce() -> Result<T, RunnerError>,
+) -> Result<T, RunnerError> {
+    let profiling_error = |e: pprof::Error| RunnerError::ProfilingError(e.to_string());
+
+    let guard = pprof::ProfilerGuardBuilder::default()
+        .frequency(10_000)
+        .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+        .build()
+        .map_err(profiling_error)?;
+    let result = f()?;
+    let report = guard.report().build().map_err(profiling_error)?;
+    if report.data.is_empty() {
+        return Err(RunnerError::ProfilingError(format!(
+            "No samples were collected for {name}, it probably ran too fast"
+        )));
+    }
+
+    let path = dir.join(format!("{name}.svg"));
+    let file = File::create(&path).map_err(|e| RunnerError::ProfilingError(e.to_string()))?;
+    report.flamegraph(file).map_err(profiling_error)?;
+    eprintln!("Wrote flamegraph to {}", path.display());
+    Ok(result)
+}
@@ -0,0 +1,228 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::RunnerError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Only the answers, one per line
+    Plain,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimedAnswer {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+/// Result of running both parts of a day.
+#[derive(Debug)]
+pub struct DayRun {
+    pub day: u8,
+    pub answers: Result<Vec<TimedAnswer>, RunnerError>,
+    /// Time for reading the input, parsing and solving.
+    pub wall_time: Duration,
+}
+
+/// Format a duration with a unit that fits its magnitude.
+pub fn format_duration(duration: Duration) -> String {
+    let micros = micros(duration);
+    if micros < 1e3 {
+        format!("{micros:.1}µs")
+    } else if micros < 1e6 {
+        format!("{:.1}ms", micros / 1e3)
+    } else {
+        format!("{:.2}s", micros / 1e6)
+    }
+}
+
+/// Render a summary table with one row per day and the total runtime of all
+/// days, which is less than the sum of the days when they ran in parallel.
+pub fn summary_table(runs: &[DayRun], total: Duration) -> String {
+    let header = [
+        "Day", "Part 1", "Part 2", "Parse", "Solve 1", "Solve 2", "Total",
+    ];
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    for run in runs {
+        let mut row = vec![format!("{:02}", run.day)];
+        match &run.answers {
+            Ok(answers) => {
+                let answer = |part| answers.iter().find(|a| a.part == part);
+                row.push(answer(1).map(|a| a.answer.clone()).unwrap_or_default());
+                row.push(answer(2).map(|a| a.answer.clone()).unwrap_or_default());
+                row.push(
+                    answers
+                        .first()
+                        .map(|a| format_duration(a.parse_time))
+                        .unwrap_or_default(),
+                );
+                row.push(
+                    answer(1)
+                        .map(|a| format_duration(a.solve_time))
+                        .unwrap_or_default(),
+                );
+                row.push(
+                    answer(2)
+                        .map(|a| format_duration(a.solve_time))
+                        .unwrap_or_default(),
+                );
+            }
+            Err(e) => {
+                row.push(format!("error: {e}"));
+                row.extend(std::iter::repeat_n(String::new(), 4));
+            }
+        }
+        row.push(format_duration(run.wall_time));
+        rows.push(row);
+    }
+
+    let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let format_row = |cells: &[String]| -> String {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        format!("{}\n", cells.join(" | ").trim_end())
+    };
+
+    let mut table = format_row(&header.map(String::from));
+    let separator: Vec<String> = widths.iter().map(|&w| "-".repeat(w)).collect();
+    table.push_str(&format!("{}\n", separator.join("-+-")));
+    for row in &rows {
+        table.push_str(&format_row(row));
+    }
+    table.push_str(&format!("\nTotal runtime: {}\n", format_duration(total)));
+    table
+}
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1e6
+}
+
+/// A single answer as a JSON object, with all times in microseconds.
+pub fn answer_json(answer: &TimedAnswer) -> serde_json::Value {
+    json!({
+        "day": answer.day,
+        "part": answer.part,
+        "answer": answer.answer,
+        "parse_time_us": micros(answer.parse_time),
+        "solve_time_us": micros(answer.solve_time),
+    })
+}
+
+/// Format the answers as a JSON array, with all times in microseconds.
+pub fn to_json(answers: &[TimedAnswer]) -> String {
+    let answers: Vec<_> = answers.iter().map(answer_json).collect();
+    serde_json::to_string_pretty(&answers).expect("Answers should be serializable")
+}
+
+/// Format the answers as CSV with a header line, with all times in
+/// microseconds.
+pub fn to_csv(answers: &[TimedAnswer]) -> String {
+    let mut csv = String::from("day,part,answer,parse_time_us,solve_time_us\n");
+    for a in answers {
+        let answer = if a.answer.contains([',', '"', '\n']) {
+            format!("\"{}\"", a.answer.replace('"', "\"\""))
+        } else {
+            a.answer.clone()
+        };
+        csv.push_str(&format!(
+            "{},{},{},{:.3},{:.3}\n",
+            a.day,
+            a.part,
+            answer,
+            micros(a.parse_time),
+            micros(a.solve_time)
+        ));
+    }
+    csv
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::*;
+
+    fn answers() -> Vec<TimedAnswer> {
+        vec![
+            TimedAnswer {
+                day: 4,
+                part: 1,
+                answer: "13".to_string(),
+                parse_time: Duration::from_micros(20),
+                solve_time: Duration::from_nanos(1500),
+            },
+            TimedAnswer {
+                day: 4,
+                part: 2,
+                answer: "a,\"b\"".to_string(),
+                parse_time: Duration::from_micros(20),
+                solve_time: Duration::from_micros(3),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(1500)), "1.5µs");
+        assert_eq!(format_duration(Duration::from_micros(12_340)), "12.3ms");
+        assert_eq!(format_duration(Duration::from_millis(2_500)), "2.50s");
+    }
+
+    #[test]
+    fn test_summary_table() {
+        let runs = vec![
+            DayRun {
+                day: 4,
+                answers: Ok(answers()),
+                wall_time: Duration::from_micros(30),
+            },
+            DayRun {
+                day: 5,
+                answers: Err(RunnerError::UnknownDay(2023, 5)),
+                wall_time: Duration::from_micros(1),
+            },
+        ];
+        assert_eq!(
+            summary_table(&runs, Duration::from_micros(31)),
+            "Day | Part 1                                  | Part 2 | Parse  | Solve 1 | Solve 2 | Total\n\
+             ----+-----------------------------------------+--------+--------+---------+---------+-------\n\
+             04  | 13                                      | a,\"b\"  | 20.0µs | 1.5µs   | 3.0µs   | 30.0µs\n\
+             05  | error: Day 5 of 2023 is not implemented |        |        |         |         | 1.0µs\n\
+             \n\
+             Total runtime: 31.0µs\n"
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&answers()),
+            "day,part,answer,parse_time_us,solve_time_us\n\
+             4,1,13,20.000,1.500\n\
+             4,2,\"a,\"\"b\"\"\",20.000,3.000\n"
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json: serde_json::Value = serde_json::from_str(&to_json(&answers())).unwrap();
+        assert_eq!(json[0]["answer"], "13");
+        assert_eq!(json[0]["solve_time_us"], 1.5);
+        assert_eq!(json[1]["part"], 2);
+    }
+}
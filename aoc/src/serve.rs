@@ -0,0 +1,93 @@
+use std::{io, net::SocketAddr};
+
+use axum::{extract::Path, http::StatusCode, routing::post, Json, Router};
+use serde_json::{json, Value};
+
+use crate::{report::answer_json, run_timed, RunnerError};
+
+type Response = (StatusCode, Json<Value>);
+
+/// The endpoints of the server:
+///
+/// - `POST /{year}/day/{day}/part/{part}` solves a part for the puzzle input
+///   in the request body. The response has the answer and the parse and solve
+///   times in microseconds, like the JSON output of `run`.
+///
+/// Errors are responded with `{"error": message}`, with status 404 for a day
+/// or part that doesn't exist and 422 for an input that can't be solved.
+pub fn router() -> Router {
+    Router::new().route("/:year/day/:day/part/:part", post(solve))
+}
+
+/// Serve the endpoints of `router` on `addr` until the process is stopped.
+pub async fn serve(addr: SocketAddr) -> io::Result<()> {
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    axum::serve(listener, router()).await
+}
+
+fn error(status: StatusCode, message: impl ToString) -> Response {
+    (status, Json(json!({ "error": message.to_string() })))
+}
+
+async fn solve(Path((year, day, part)): Path<(u16, u8, u8)>, input: String) -> Response {
+    if !(1..=2).contains(&part) {
+        return error(StatusCode::NOT_FOUND, format!("Part {part} does not exist"));
+    }
+    // the solvers block, so they don't run on the async worker threads
+    let result =
+        tokio::task::spawn_blocking(move || run_timed(year, day, Some(part), &[input])).await;
+    match result {
+        Ok(Ok(answers)) => (StatusCode::OK, Json(answer_json(&answers[0]))),
+        Ok(Err(e @ RunnerError::UnknownDay(..))) => error(StatusCode::NOT_FOUND, e),
+        Ok(Err(e)) => error(StatusCode::UNPROCESSABLE_ENTITY, e),
+        // the solver panicked
+        Err(e) => error(StatusCode::INTERNAL_SERVER_ERROR, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::serve::*;
+    use axum::{body::Body, http::Request};
+    use http_body_util::BodyExt;
+    use std::fs;
+    use tower::ServiceExt;
+
+    async fn post(uri: &str, body: String) -> (StatusCode, Value) {
+        let request = Request::post(uri).body(Body::from(body)).unwrap();
+        let response = router().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_solve() {
+        let input =
+            fs::read_to_string("../2023/day_04/input_test.txt").expect("Could not read the file");
+        let (status, json) = post("/2023/day/4/part/2", input.clone()).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(json["day"], 4);
+        assert_eq!(json["part"], 2);
+        assert_eq!(json["answer"], "30");
+        assert!(json["solve_time_us"].is_f64());
+
+        for (uri, status, message) in [
+            ("/2022/day/4/part/1", StatusCode::NOT_FOUND, "Day 4 of 2022"),
+            ("/2023/day/26/part/1", StatusCode::NOT_FOUND, "Day 26"),
+            ("/2023/day/4/part/3", StatusCode::NOT_FOUND, "Part 3"),
+            (
+                "/2023/day/9/part/1",
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "Line 1",
+            ),
+        ] {
+            let (actual, json) = post(uri, input.clone()).await;
+            assert_eq!(actual, status, "{uri}");
+            assert!(
+                json["error"].as_str().unwrap().starts_with(message),
+                "{json}"
+            );
+        }
+    }
+}
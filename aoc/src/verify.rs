@@ -0,0 +1,213 @@
+use std::{
+    fmt::Display,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{days, default_input, run, RunnerError};
+
+/// Contents of `answers.toml`.
+#[derive(Debug, Deserialize)]
+pub struct AnswersFile {
+    pub days: Vec<DayAnswers>,
+}
+
+impl AnswersFile {
+    /// The expected answer of a part, if it is known.
+    pub fn expected(&self, day: u8, kind: InputKind, part: u8) -> Option<&String> {
+        let answers = self.days.iter().find(|a| a.day == day)?;
+        match kind {
+            InputKind::Test => answers.test.as_ref(),
+            InputKind::Real => answers.real.as_ref(),
+        }?
+        .get(part)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DayAnswers {
+    pub day: u8,
+    pub test: Option<PartAnswers>,
+    pub real: Option<PartAnswers>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PartAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl PartAnswers {
+    fn get(&self, part: u8) -> Option<&String> {
+        match part {
+            1 => self.part1.as_ref(),
+            _ => self.part2.as_ref(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputKind {
+    Test,
+    Real,
+}
+
+impl Display for InputKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputKind::Test => write!(f, "test"),
+            InputKind::Real => write!(f, "real"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Verification {
+    pub day: u8,
+    pub kind: InputKind,
+    pub part: u8,
+    pub expected: String,
+    /// The computed answer, or a description of why it could not be computed.
+    pub actual: Result<String, String>,
+}
+
+impl Verification {
+    pub fn passed(&self) -> bool {
+        self.actual.as_ref() == Ok(&self.expected)
+    }
+}
+
+impl Display for Verification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {:02} part {} ({}): ",
+            self.day, self.part, self.kind
+        )?;
+        match &self.actual {
+            _ if self.passed() => write!(f, "ok"),
+            Ok(actual) => write!(f, "FAIL (expected {}, got {actual})", self.expected),
+            Err(e) => write!(f, "FAIL ({e})"),
+        }
+    }
+}
+
+pub fn load_answers(path: &Path) -> Result<AnswersFile, RunnerError> {
+    let content =
+        fs::read_to_string(path).map_err(|e| RunnerError::ReadInputError(path.to_path_buf(), e))?;
+    toml::from_str(&content).map_err(|e| RunnerError::AnswersParseError(e.to_string()))
+}
+
+/// Path of the input of a part relative to the workspace `root`. Days with a
+/// separate example for part 2 have it in `input_test_part2.txt`.
+fn input_path(root: &Path, year: u16, day: u8, kind: InputKind, part: u8) -> PathBuf {
+    match kind {
+        InputKind::Test => {
+            let dir = root.join(format!("{year}/day_{day:02}"));
+            let part2 = dir.join("input_test_part2.txt");
+            match part {
+                2 if part2.exists() => part2,
+                _ => dir.join("input_test.txt"),
+            }
+        }
+        InputKind::Real => root.join(default_input(year, day, Some(part))),
+    }
+}
+
+/// Run every implemented day of `year` against all expected answers, with
+/// the input files located relative to the workspace `root`. The real inputs
+/// are not checked in everywhere, the answers of missing ones are skipped.
+pub fn verify(answers: &AnswersFile, root: &Path, year: u16) -> Vec<Verification> {
+    let mut verifications = Vec::new();
+    for day in days(year) {
+        let Some(day_answers) = answers.days.iter().find(|a| a.day == day) else {
+            continue;
+        };
+        for (kind, expected) in [
+            (InputKind::Test, &day_answers.test),
+            (InputKind::Real, &day_answers.real),
+        ] {
+            let Some(expected) = expected else {
+                continue;
+            };
+            for part in 1..=2 {
+                let Some(expected) = expected.get(part) else {
+                    continue;
+                };
+                let path = input_path(root, year, day, kind, part);
+                if kind == InputKind::Real && !path.exists() {
+                    continue;
+                }
+                let actual = fs::read_to_string(path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|input| {
+                        run(year, day, Some(part), &[input]).map_err(|e| e.to_string())
+                    })
+                    .map(|mut answers| answers.remove(0));
+                verifications.push(Verification {
+                    day,
+                    kind,
+                    part,
+                    expected: expected.clone(),
+                    actual,
+                });
+            }
+        }
+    }
+    verifications
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::verify::*;
+    use std::{env, process};
+
+    #[test]
+    fn test_verify() {
+        let answers: AnswersFile = toml::from_str(
+            r#"
+            [[days]]
+            day = 4
+            test = { part1 = "13", part2 = "31" }
+            "#,
+        )
+        .unwrap();
+        let verifications = verify(&answers, Path::new(".."), 2023);
+        assert_eq!(verifications.len(), 2);
+        assert!(verifications[0].passed());
+        assert!(!verifications[1].passed());
+        assert_eq!(
+            verifications[1].to_string(),
+            "Day 04 part 2 (test): FAIL (expected 31, got 30)"
+        );
+        assert_eq!(answers.expected(4, InputKind::Test, 2).unwrap(), "31");
+        assert_eq!(answers.expected(4, InputKind::Real, 2), None);
+        assert_eq!(answers.expected(5, InputKind::Test, 1), None);
+
+        let day_08_answers: AnswersFile = toml::from_str(
+            r#"
+            [[days]]
+            day = 8
+            test = { part1 = "6", part2 = "6" }
+            real = { part1 = "1" }
+            "#,
+        )
+        .unwrap();
+        let root = env::temp_dir().join(format!("aoc-verify-test-{}", process::id()));
+        fs::create_dir_all(root.join("2023/day_08")).unwrap();
+        for file in ["input_test.txt", "input_test_part2.txt"] {
+            fs::copy(
+                Path::new("../2023/day_08").join(file),
+                root.join("2023/day_08").join(file),
+            )
+            .unwrap();
+        }
+        // the example of part 2 is used, the missing real input is skipped
+        let verifications = verify(&day_08_answers, &root, 2023);
+        assert_eq!(verifications.len(), 2);
+        assert!(verifications.iter().all(|v| v.passed()));
+        fs::remove_dir_all(root).unwrap();
+    }
+}
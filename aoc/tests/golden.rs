@@ -0,0 +1,33 @@
+//! Runs both parts of every day against the expected answers in the
+//! `answers.toml` of its year, on the examples and on the real inputs that
+//! are checked in.
+
+use std::path::Path;
+
+use aoc::{
+    days,
+    verify::{load_answers, verify, InputKind},
+    years,
+};
+
+#[test]
+fn test_answers() {
+    let root = Path::new("..");
+    for year in years() {
+        let answers = load_answers(&root.join(format!("{year}/answers.toml"))).unwrap();
+        for day in days(year) {
+            assert!(
+                answers.expected(day, InputKind::Test, 1).is_some(),
+                "Day {day} of {year} has no expected answer for the example"
+            );
+        }
+
+        let verifications = verify(&answers, root, year);
+        let failures: Vec<String> = verifications
+            .iter()
+            .filter(|v| !v.passed())
+            .map(|v| format!("{year} {v}"))
+            .collect();
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
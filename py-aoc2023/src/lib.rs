@@ -0,0 +1,214 @@
+//! Python bindings of the solutions, e.g. to analyze the inputs in a
+//! notebook. Build and install them into the current virtualenv with
+//! `maturin develop -m py-aoc2023/Cargo.toml`, then `import aoc2023`.
+//!
+//! Every day can be solved with `solve`. The days with structured data also
+//! get a function returning it as lists, dicts and ints. Errors are raised as
+//! `ValueError` with the message of the day.
+
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyDict, PyList},
+};
+
+/// The year of the days of the module.
+const YEAR: u16 = 2023;
+
+/// The days that can be solved, as a list (a `Vec<u8>` would become `bytes`).
+#[pyfunction]
+fn days() -> Vec<u32> {
+    aoc_days::days_of(YEAR)
+        .iter()
+        .map(|day| day.day.into())
+        .collect()
+}
+
+fn value_error(error: impl ToString) -> PyErr {
+    PyValueError::new_err(error.to_string())
+}
+
+/// Solve `part` of `day` for the puzzle input. If the input can't be parsed
+/// or the answer could not be computed, the error of the day is raised.
+#[pyfunction]
+fn solve(day: u8, part: u8, input: &str) -> PyResult<i128> {
+    let registered = aoc_days::day(YEAR, day)
+        .ok_or_else(|| value_error(format!("Day {day} is not implemented")))?;
+    if !(1..=2).contains(&part) {
+        return Err(value_error(format!("Part {part} does not exist")));
+    }
+    let parsed = registered.parse(input).map_err(value_error)?;
+    let answer = match part {
+        1 => parsed.part1(),
+        _ => parsed.part2(),
+    }
+    .map_err(value_error)?;
+    answer.parse().map_err(|_| value_error(answer))
+}
+
+/// The games of day 2, each a dict with the `id` and the `draws`, which map
+/// colors to counts.
+#[pyfunction]
+fn day02_games<'py>(py: Python<'py>, input: &str) -> PyResult<Bound<'py, PyList>> {
+    let games = aoc2023_day_02::parse_input(input).map_err(value_error)?;
+    let list = PyList::empty(py);
+    for game in games {
+        let draws = PyList::empty(py);
+        for draw in game.draws() {
+            let colors = PyDict::new(py);
+            for (color, count) in draw.colors() {
+                colors.set_item(color, count)?;
+            }
+            draws.append(colors)?;
+        }
+        let dict = PyDict::new(py);
+        dict.set_item("id", game.id())?;
+        dict.set_item("draws", draws)?;
+        list.append(dict)?;
+    }
+    Ok(list)
+}
+
+/// The schematic report of day 3: every `number` with its position and
+/// adjacent symbols, and every `gear` symbol with its adjacent numbers.
+#[pyfunction]
+fn day03_report<'py>(py: Python<'py>, input: &str) -> PyResult<Bound<'py, PyDict>> {
+    let schematic = aoc2023_day_03::parse_input(input).map_err(value_error)?;
+    let report = aoc2023_day_03::analyze(&schematic).map_err(value_error)?;
+    let numbers = PyList::empty(py);
+    for number in &report.numbers {
+        let symbols = PyList::empty(py);
+        for symbol in &number.symbols {
+            let dict = PyDict::new(py);
+            dict.set_item("x", symbol.x)?;
+            dict.set_item("y", symbol.y)?;
+            dict.set_item("char", symbol.char)?;
+            symbols.append(dict)?;
+        }
+        let dict = PyDict::new(py);
+        dict.set_item("value", number.value)?;
+        dict.set_item("y", number.y)?;
+        dict.set_item("x_start", number.x_start)?;
+        dict.set_item("x_end", number.x_end)?;
+        dict.set_item("symbols", symbols)?;
+        numbers.append(dict)?;
+    }
+    let gears = PyList::empty(py);
+    for gear in &report.gears {
+        let dict = PyDict::new(py);
+        dict.set_item("x", gear.x)?;
+        dict.set_item("y", gear.y)?;
+        dict.set_item("numbers", &gear.numbers)?;
+        gears.append(dict)?;
+    }
+    let dict = PyDict::new(py);
+    dict.set_item("numbers", numbers)?;
+    dict.set_item("gears", gears)?;
+    dict.set_item("gear_arity", report.gear_arity)?;
+    Ok(dict)
+}
+
+/// The card report of day 4, with the matching numbers, the points and the
+/// number of copies of every card.
+#[pyfunction]
+fn day04_report<'py>(py: Python<'py>, input: &str) -> PyResult<Bound<'py, PyList>> {
+    let cards = aoc2023_day_04::parse_input(input).map_err(value_error)?;
+    let list = PyList::empty(py);
+    for card in aoc2023_day_04::report(&cards).map_err(value_error)? {
+        let dict = PyDict::new(py);
+        dict.set_item("id", card.id)?;
+        dict.set_item("matching_numbers", card.matching_numbers)?;
+        dict.set_item("points", card.points)?;
+        dict.set_item("copies", card.copies)?;
+        list.append(dict)?;
+    }
+    Ok(list)
+}
+
+/// The id of `seed` in every category of day 5, as `(category, id)` pairs
+/// from seed to location.
+#[pyfunction]
+fn day05_trace(input: &str, seed: usize) -> PyResult<Vec<(String, usize)>> {
+    aoc2023_day_05::parse_input(input)
+        .and_then(|almanac| almanac.trace(seed))
+        .map_err(value_error)
+}
+
+/// The races of day 6 as `(time, distance)` pairs.
+#[pyfunction]
+fn day06_races(input: &str) -> PyResult<Vec<(u64, u64)>> {
+    let races = aoc2023_day_06::parse_input(input).map_err(value_error)?;
+    Ok(races
+        .races()
+        .iter()
+        .map(|race| (race.time(), race.distance()))
+        .collect())
+}
+
+/// The histories of day 9.
+#[pyfunction]
+fn day09_histories(input: &str) -> PyResult<Vec<Vec<i64>>> {
+    aoc2023_day_09::parse_input(input).map_err(value_error)
+}
+
+#[pymodule]
+fn aoc2023(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(days, m)?)?;
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_function(wrap_pyfunction!(day02_games, m)?)?;
+    m.add_function(wrap_pyfunction!(day03_report, m)?)?;
+    m.add_function(wrap_pyfunction!(day04_report, m)?)?;
+    m.add_function(wrap_pyfunction!(day05_trace, m)?)?;
+    m.add_function(wrap_pyfunction!(day06_races, m)?)?;
+    m.add_function(wrap_pyfunction!(day09_histories, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn test_solve() {
+        let input =
+            fs::read_to_string("../2023/day_09/input_test.txt").expect("Could not read the file");
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            assert_eq!(solve(9, 1, &input).unwrap(), 114);
+            assert_eq!(solve(9, 2, &input).unwrap(), 2);
+            let error = solve(9, 3, &input).unwrap_err();
+            assert_eq!(error.value(py).to_string(), "Part 3 does not exist");
+            let error = solve(26, 1, &input).unwrap_err();
+            assert_eq!(error.value(py).to_string(), "Day 26 is not implemented");
+            let error = solve(9, 1, "1 x").unwrap_err();
+            assert!(error.value(py).to_string().starts_with("Line 1"));
+            assert_eq!(days().len(), 22);
+        });
+    }
+
+    #[test]
+    fn test_day04_report() {
+        let input =
+            fs::read_to_string("../2023/day_04/input_test.txt").expect("Could not read the file");
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let report = day04_report(py, &input).unwrap();
+            assert_eq!(report.len(), 6);
+            let card = report.get_item(0).unwrap();
+            assert_eq!(card.get_item("id").unwrap().extract::<usize>().unwrap(), 1);
+            assert_eq!(
+                card.get_item("matching_numbers")
+                    .unwrap()
+                    .extract::<Vec<usize>>()
+                    .unwrap()
+                    .len(),
+                4
+            );
+            assert_eq!(
+                card.get_item("points").unwrap().extract::<usize>().unwrap(),
+                8
+            );
+        });
+    }
+}
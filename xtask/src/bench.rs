@@ -0,0 +1,263 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+    time::SystemTime,
+};
+
+use serde::Deserialize;
+
+use crate::XtaskError;
+
+/// The part of criterion's `estimates.json` that is compared.
+#[derive(Debug, Deserialize)]
+struct Estimates {
+    median: Estimate,
+}
+
+#[derive(Debug, Deserialize)]
+struct Estimate {
+    /// In nanoseconds.
+    point_estimate: f64,
+}
+
+/// The median times of one benchmark, like `2023_day_05/part2`, in the baseline
+/// and in the latest run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub name: String,
+    pub baseline_ns: f64,
+    pub new_ns: f64,
+}
+
+impl Comparison {
+    /// The change of the time in percent, positive if it got slower.
+    pub fn change(&self) -> f64 {
+        (self.new_ns / self.baseline_ns - 1.0) * 100.0
+    }
+}
+
+fn read_median(path: &Path) -> Result<f64, XtaskError> {
+    let json = fs::read_to_string(path)?;
+    let estimates: Estimates = serde_json::from_str(&json)
+        .map_err(|e| XtaskError::InvalidEstimates(path.to_path_buf(), e.to_string()))?;
+    Ok(estimates.median.point_estimate)
+}
+
+fn sub_dirs(dir: &Path) -> Result<Vec<PathBuf>, XtaskError> {
+    let mut dirs = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        // criterion puts its html reports next to the results
+        if path.is_dir() && !path.ends_with("report") {
+            dirs.push(path);
+        }
+    }
+    dirs.sort();
+    Ok(dirs)
+}
+
+/// The directories of all benchmarks in the `criterion` output directory.
+fn benches(criterion: &Path) -> Vec<PathBuf> {
+    let groups = sub_dirs(criterion).unwrap_or_default();
+    groups
+        .iter()
+        .flat_map(|group| sub_dirs(group).unwrap_or_default())
+        .collect()
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// When the latest results of the benchmarks were written.
+pub fn latest_results(criterion: &Path) -> HashMap<PathBuf, SystemTime> {
+    benches(criterion)
+        .into_iter()
+        .filter_map(|bench| {
+            let new = bench.join("new/estimates.json");
+            modified(&new).map(|time| (new, time))
+        })
+        .collect()
+}
+
+/// Compare the results of the benchmarks in the `criterion` output directory
+/// that changed since `before` was taken with `latest_results` with their
+/// results in `baseline`.
+pub fn compare(
+    criterion: &Path,
+    baseline: &str,
+    before: &HashMap<PathBuf, SystemTime>,
+) -> Result<Vec<Comparison>, XtaskError> {
+    let mut comparisons = Vec::new();
+    for bench in benches(criterion) {
+        let new = bench.join("new/estimates.json");
+        let old = bench.join(baseline).join("estimates.json");
+        // benchmarks of days without an input are skipped and keep the
+        // results of an earlier run
+        let is_fresh = modified(&new).is_some_and(|time| before.get(&new) != Some(&time));
+        if !is_fresh || !old.exists() {
+            continue;
+        }
+        let name = bench
+            .strip_prefix(criterion)
+            .unwrap_or(&bench)
+            .to_string_lossy()
+            .into_owned();
+        comparisons.push(Comparison {
+            name,
+            baseline_ns: read_median(&old)?,
+            new_ns: read_median(&new)?,
+        });
+    }
+    Ok(comparisons)
+}
+
+fn format_ns(ns: f64) -> String {
+    match ns {
+        ns if ns < 1e3 => format!("{ns:.1}ns"),
+        ns if ns < 1e6 => format!("{:.1}µs", ns / 1e3),
+        ns if ns < 1e9 => format!("{:.1}ms", ns / 1e6),
+        ns => format!("{:.2}s", ns / 1e9),
+    }
+}
+
+/// A table of the comparisons, the ones slower than `threshold` percent are
+/// marked as regressed.
+pub fn report(comparisons: &[Comparison], threshold: f64) -> String {
+    let width = comparisons
+        .iter()
+        .map(|c| c.name.len())
+        .max()
+        .unwrap_or(0)
+        .max("Benchmark".len());
+    let mut report = format!(
+        "{:<width$}  {:>10}  {:>10}  {:>8}\n",
+        "Benchmark", "Baseline", "New", "Change"
+    );
+    for comparison in comparisons {
+        let change = comparison.change();
+        let line = format!(
+            "{:<width$}  {:>10}  {:>10}  {:>+7.1}%",
+            comparison.name,
+            format_ns(comparison.baseline_ns),
+            format_ns(comparison.new_ns),
+            change,
+        );
+        report.push_str(&line);
+        if change > threshold {
+            report.push_str("  REGRESSED");
+        }
+        report.push('\n');
+    }
+    report
+}
+
+/// Run the benchmarks of the days. The first run for a `baseline` saves it,
+/// later runs are compared with it and fail if a benchmark got more than
+/// `threshold` percent slower. With `save` the baseline is replaced by the
+/// results of the run, then only the benchmarks selected by `criterion_args`
+/// are replaced.
+pub fn bench(
+    root: &Path,
+    baseline: &str,
+    threshold: f64,
+    save: bool,
+    criterion_args: &[String],
+) -> Result<(), XtaskError> {
+    let criterion = std::env::var_os("CARGO_TARGET_DIR")
+        .map_or_else(|| root.join("target"), PathBuf::from)
+        .join("criterion");
+    let exists = benches(&criterion)
+        .iter()
+        .any(|bench| bench.join(baseline).exists());
+    let save = save || !exists;
+
+    let before = latest_results(&criterion);
+    let status = Command::new(env!("CARGO"))
+        .current_dir(root)
+        .args(["bench", "-p", "aoc-bench", "--bench", "days", "--"])
+        .arg(if save {
+            "--save-baseline"
+        } else {
+            "--baseline"
+        })
+        .arg(baseline)
+        .args(criterion_args)
+        .status()?;
+    if !status.success() {
+        return Err(XtaskError::BenchFailed(status));
+    }
+    if save {
+        println!("Saved the baseline `{baseline}`");
+        return Ok(());
+    }
+
+    let comparisons = compare(&criterion, baseline, &before)?;
+    print!("{}", report(&comparisons, threshold));
+    let regressions = comparisons
+        .iter()
+        .filter(|c| c.change() > threshold)
+        .count();
+    match regressions {
+        0 => Ok(()),
+        n => Err(XtaskError::BenchRegressed(n, threshold)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::*;
+    use std::env;
+
+    fn write_estimates(path: &Path, median: f64) {
+        fs::create_dir_all(path).unwrap();
+        let json = format!(
+            r#"{{"mean": {{"point_estimate": 0.0}}, "median": {{"point_estimate": {median}}}}}"#
+        );
+        fs::write(path.join("estimates.json"), json).unwrap();
+    }
+
+    #[test]
+    fn test_compare() {
+        let criterion = env::temp_dir().join("xtask-bench-test");
+        let _ = fs::remove_dir_all(&criterion);
+        write_estimates(&criterion.join("day_01/parse/main"), 1000.0);
+        write_estimates(&criterion.join("day_01/part1/main"), 2000.0);
+        // from an earlier run
+        write_estimates(&criterion.join("day_02/parse/main"), 10.0);
+        write_estimates(&criterion.join("day_02/parse/new"), 20.0);
+        fs::create_dir_all(criterion.join("report")).unwrap();
+        fs::create_dir_all(criterion.join("day_01/report")).unwrap();
+
+        let before = latest_results(&criterion);
+        write_estimates(&criterion.join("day_01/parse/new"), 1200.0);
+        write_estimates(&criterion.join("day_01/part1/new"), 1500.0);
+
+        let comparisons = compare(&criterion, "main", &before).unwrap();
+        assert_eq!(
+            comparisons,
+            [
+                Comparison {
+                    name: "day_01/parse".to_string(),
+                    baseline_ns: 1000.0,
+                    new_ns: 1200.0
+                },
+                Comparison {
+                    name: "day_01/part1".to_string(),
+                    baseline_ns: 2000.0,
+                    new_ns: 1500.0
+                },
+            ]
+        );
+        assert!((comparisons[0].change() - 20.0).abs() < 1e-9);
+        assert_eq!(
+            report(&comparisons, 10.0),
+            "Benchmark       Baseline         New    Change\n\
+             day_01/parse       1.0µs       1.2µs    +20.0%  REGRESSED\n\
+             day_01/part1       2.0µs       1.5µs    -25.0%\n"
+        );
+        assert!(compare(&criterion, "other", &before).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,359 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    process::ExitStatus,
+};
+
+use clap::{Parser, Subcommand};
+use thiserror::Error;
+
+mod bench;
+
+#[derive(Error, Debug)]
+enum XtaskError {
+    #[error("Directory `{0}` already exists")]
+    DayExists(PathBuf),
+    #[error("Could not find the members list in the workspace Cargo.toml")]
+    MembersNotFound,
+    #[error("Could not find the dependencies in the aoc-days Cargo.toml")]
+    DependenciesNotFound,
+    #[error("The benchmarks failed: {0}")]
+    BenchFailed(ExitStatus),
+    #[error("Could not read the criterion estimates `{0}`: {1}")]
+    InvalidEstimates(PathBuf, String),
+    #[error("{0} benchmarks are more than {1}% slower than the baseline")]
+    BenchRegressed(usize, f64),
+    #[error("The build without std failed: {0}")]
+    NoStdFailed(ExitStatus),
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+}
+
+#[derive(Parser)]
+#[command(about = "Development tasks for the workspace")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create the crate for a new day in the directory of its year, like
+    /// `2023/day_05`, and add it to the workspace
+    NewDay {
+        #[arg(long)]
+        year: u16,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Run the benchmarks and compare them with a saved criterion baseline,
+    /// the first run saves it
+    Bench {
+        /// Name of the baseline
+        #[arg(long, default_value = "main")]
+        baseline: String,
+        /// Fail if a benchmark is slower by more than this many percent
+        #[arg(long, default_value_t = 5.0)]
+        threshold: f64,
+        /// Replace the baseline with the results of this run
+        #[arg(long)]
+        save: bool,
+        /// More arguments for criterion, like a filter of the benchmarks
+        #[arg(last = true)]
+        criterion_args: Vec<String>,
+    },
+    /// Build aoc-common and the days without std, to check that they still
+    /// run on embedded targets
+    NoStd {
+        /// Target without std, it must be installed with `rustup target add`
+        #[arg(long, default_value = "thumbv7em-none-eabihf")]
+        target: String,
+    },
+}
+
+const CARGO_TOML: &str = r#"[package]
+name = "aoc{year}_day_{day}"
+version = "0.1.0"
+edition = "2021"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[[bin]]
+name = "aoc{year}_day_{day}_part1"
+path = "src/part1.rs"
+required-features = ["std"]
+
+[[bin]]
+name = "aoc{year}_day_{day}_part2"
+path = "src/part2.rs"
+required-features = ["std"]
+
+[dependencies]
+aoc-common = { path = "../../aoc-common", default-features = false }
+
+[features]
+default = ["std"]
+std = ["aoc-common/std"]
+"#;
+
+const LIB_RS: &str = r#"#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec::Vec};
+
+use aoc_common::{Answer, Solution, SolveError};
+
+pub fn parse_input(input: &str) -> Vec<&str> {
+    input.trim().split('\n').collect()
+}
+
+pub fn process_part1(input: &[&str]) -> usize {
+    input.len()
+}
+
+pub fn process_part2(input: &[&str]) -> usize {
+    input.len()
+}
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day_number};
+
+    type Parsed<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Parsed<'_>, SolveError> {
+        Ok(parse_input(input))
+    }
+
+    fn part1(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part1(parsed).to_string())
+    }
+
+    fn part2(parsed: &Self::Parsed<'_>) -> Answer {
+        Ok(process_part2(parsed).to_string())
+    }
+}
+
+aoc_common::register_day!(Day{day});
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn test_process_part1() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input);
+        let output = process_part1(&parsed_input);
+        assert_eq!(output, 1)
+    }
+
+    #[test]
+    fn test_process_part2() {
+        let input = fs::read_to_string("input_test.txt").expect("Could not read the file");
+        let parsed_input = parse_input(&input);
+        let output = process_part2(&parsed_input);
+        assert_eq!(output, 1)
+    }
+}
+"#;
+
+const PART_RS: &str = "aoc_common::aoc_main!(aoc{year}_day_{day}::Day{day}, part{part});\n";
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask should be inside the workspace")
+        .to_path_buf()
+}
+
+/// Insert `member` into the members list of the workspace manifest, keeping
+/// the list sorted.
+fn add_workspace_member(manifest: &str, member: &str) -> Result<String, XtaskError> {
+    let (head, rest) = manifest
+        .split_once("members = [")
+        .ok_or(XtaskError::MembersNotFound)?;
+    let (members, tail) = rest.split_once(']').ok_or(XtaskError::MembersNotFound)?;
+
+    let mut members: Vec<String> = members
+        .split(',')
+        .map(|m| m.trim().trim_matches('"').to_string())
+        .filter(|m| !m.is_empty())
+        .collect();
+    if !members.iter().any(|m| m == member) {
+        members.push(member.to_string());
+    }
+    members.sort();
+
+    let members: String = members.iter().map(|m| format!("    \"{m}\",\n")).collect();
+    Ok(format!("{head}members = [\n{members}]{tail}"))
+}
+
+/// Add the crate `name` of a day in the directory `path` to the dependencies
+/// of aoc-days, keeping the days sorted, so that the runners find it.
+fn add_day_dependency(manifest: &str, name: &str, path: &str) -> Result<String, XtaskError> {
+    let dependency = format!("{name} = {{ path = \"../{path}\" }}");
+    let mut lines: Vec<&str> = manifest.lines().collect();
+    if !lines.contains(&dependency.as_str()) {
+        let position = lines
+            .iter()
+            .rposition(|line| line.contains("_day_") && *line < dependency.as_str())
+            .or_else(|| lines.iter().position(|line| *line == "[dependencies]"))
+            .ok_or(XtaskError::DependenciesNotFound)?;
+        lines.insert(position + 1, &dependency);
+    }
+    Ok(lines.join("\n") + "\n")
+}
+
+fn new_day(root: &Path, year: u16, day: u8) -> Result<(), XtaskError> {
+    let path = format!("{year}/day_{day:02}");
+    let name = format!("aoc{year}_day_{day:02}");
+    let dir = root.join(&path);
+    if dir.exists() {
+        return Err(XtaskError::DayExists(dir));
+    }
+
+    let day_number = day.to_string();
+    let day = format!("{day:02}");
+    let year = year.to_string();
+    let fill = |template: &str| template.replace("{year}", &year).replace("{day}", &day);
+    fs::create_dir_all(dir.join("src"))?;
+    fs::write(dir.join("Cargo.toml"), fill(CARGO_TOML))?;
+    let lib = fill(LIB_RS).replace("{day_number}", &day_number);
+    fs::write(dir.join("src/lib.rs"), lib)?;
+    for part in ["1", "2"] {
+        let source = fill(PART_RS).replace("{part}", part);
+        fs::write(dir.join(format!("src/part{part}.rs")), source)?;
+    }
+    fs::write(dir.join("input_test.txt"), "")?;
+
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)?;
+    let member = format!("{year}/day_*");
+    fs::write(&manifest_path, add_workspace_member(&manifest, &member)?)?;
+    let days_path = root.join("aoc-days/Cargo.toml");
+    let days = fs::read_to_string(&days_path)?;
+    fs::write(&days_path, add_day_dependency(&days, &name, &path)?)?;
+
+    println!("Created {path}, the runners find it through aoc-days");
+    Ok(())
+}
+
+/// The names of the crates of the days in the year directories, sorted.
+fn day_crates(root: &Path) -> Result<Vec<String>, XtaskError> {
+    let mut days = Vec::new();
+    for entry in fs::read_dir(root)? {
+        let entry = entry?;
+        let year = entry.file_name().to_string_lossy().into_owned();
+        if year.parse::<u16>().is_err() || !entry.path().is_dir() {
+            continue;
+        }
+        for day in fs::read_dir(entry.path())? {
+            let day = day?.file_name().to_string_lossy().into_owned();
+            if day.starts_with("day_") {
+                days.push(format!("aoc{year}_{day}"));
+            }
+        }
+    }
+    days.sort();
+    Ok(days)
+}
+
+fn no_std(root: &Path, target: &str) -> Result<(), XtaskError> {
+    let mut build = std::process::Command::new(env!("CARGO"));
+    build
+        .current_dir(root)
+        .args([
+            "build",
+            "--lib",
+            "--no-default-features",
+            "--target",
+            target,
+        ])
+        .args(["-p", "aoc-common"]);
+    for day in day_crates(root)? {
+        build.args(["-p", &day]);
+    }
+    let status = build.status()?;
+    if !status.success() {
+        return Err(XtaskError::NoStdFailed(status));
+    }
+    println!("aoc-common and the days build without std for {target}");
+    Ok(())
+}
+
+fn main() -> Result<(), XtaskError> {
+    let cli = Cli::parse();
+    match cli.command {
+        Command::NewDay { year, day } => new_day(&workspace_root(), year, day),
+        Command::Bench {
+            baseline,
+            threshold,
+            save,
+            criterion_args,
+        } => bench::bench(
+            &workspace_root(),
+            &baseline,
+            threshold,
+            save,
+            &criterion_args,
+        ),
+        Command::NoStd { target } => no_std(&workspace_root(), &target),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_add_workspace_member() {
+        let manifest = "[workspace]\nmembers = [\n    \"2023/day_*\",\n    \"aoc\",\n]\n";
+        assert_eq!(
+            add_workspace_member(manifest, "2024/day_*").unwrap(),
+            "[workspace]\nmembers = [\n    \"2023/day_*\",\n    \"2024/day_*\",\n    \"aoc\",\n]\n"
+        );
+        assert_eq!(
+            add_workspace_member(manifest, "2023/day_*").unwrap(),
+            manifest
+        );
+        assert!(matches!(
+            add_workspace_member("[package]", "2024/day_*"),
+            Err(XtaskError::MembersNotFound)
+        ));
+    }
+
+    #[test]
+    fn test_add_day_dependency() {
+        let common = "aoc-common = { path = \"../aoc-common\" }\n";
+        let day_07 = "aoc2023_day_07 = { path = \"../2023/day_07\" }\n";
+        let day_08 = "aoc2023_day_08 = { path = \"../2023/day_08\" }\n";
+        let manifest = format!("[dependencies]\n{common}");
+        let with_day = add_day_dependency(&manifest, "aoc2023_day_08", "2023/day_08").unwrap();
+        assert_eq!(with_day, format!("[dependencies]\n{day_08}{common}"));
+        assert_eq!(
+            add_day_dependency(&with_day, "aoc2023_day_07", "2023/day_07").unwrap(),
+            format!("[dependencies]\n{day_07}{day_08}{common}")
+        );
+        assert_eq!(
+            add_day_dependency(&with_day, "aoc2023_day_08", "2023/day_08").unwrap(),
+            with_day
+        );
+        assert!(matches!(
+            add_day_dependency("[package]", "aoc2023_day_07", "2023/day_07"),
+            Err(XtaskError::DependenciesNotFound)
+        ));
+    }
+
+    #[test]
+    fn test_day_crates() {
+        let days = day_crates(&workspace_root()).unwrap();
+        assert_eq!(days[0], "aoc2023_day_01");
+        assert!(days.contains(&"aoc2023_day_21".to_string()));
+        assert!(days.windows(2).all(|w| w[0] < w[1]));
+    }
+}